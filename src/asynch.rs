@@ -0,0 +1,200 @@
+//! Async adapter for `embedded-io-async` readers
+//!
+//! Enabled with the `embedded-io-async` feature. [`NmeaReader`] reads from
+//! any [`embedded_io_async::Read`] implementation (e.g. an Embassy UART) into
+//! a [`StreamParser`] and yields complete messages.
+
+use core::fmt;
+
+use embedded_io_async::Read;
+
+use crate::decoder::{MessageDecoder, NoDecoder};
+use crate::parser::NmeaParser;
+use crate::stream::StreamParser;
+use crate::types::ParseError;
+
+/// Error returned by `NmeaReader::next_message`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ReadError<E> {
+    /// The underlying reader failed
+    Io(E),
+    /// A complete sentence was received but it's invalid
+    Parse(ParseError),
+    /// The underlying reader reached end of file
+    Eof,
+}
+
+impl<E: fmt::Debug> fmt::Display for ReadError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(err) => write!(f, "read error: {:?}", err),
+            ReadError::Parse(err) => write!(f, "parse error: {}", err),
+            ReadError::Eof => f.write_str("end of input"),
+        }
+    }
+}
+
+impl<E: fmt::Debug> core::error::Error for ReadError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ReadError::Parse(err) => Some(err),
+            ReadError::Io(_) | ReadError::Eof => None,
+        }
+    }
+}
+
+/// Async reader yielding NMEA messages from an `embedded-io-async` source,
+/// buffering up to `N` bytes of partial sentence
+///
+/// # Example
+///
+/// ```
+/// # async fn example() {
+/// use rustedbytes_nmea::asynch::NmeaReader;
+///
+/// let uart: &[u8] = b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+/// let mut reader = NmeaReader::<_, 128>::new(uart);
+///
+/// while let Ok(msg) = reader.next_message().await {
+///     // Process complete message
+///     let _ = msg.message_type();
+/// }
+/// # }
+/// ```
+pub struct NmeaReader<R, const N: usize, D = NoDecoder> {
+    reader: R,
+    stream: StreamParser<N, D>,
+}
+
+impl<R: Read, const N: usize> NmeaReader<R, N> {
+    /// Create a reader using a default `NmeaParser`
+    pub fn new(reader: R) -> Self {
+        Self::with_parser(reader, NmeaParser::new())
+    }
+}
+
+impl<R: Read, const N: usize, D: MessageDecoder> NmeaReader<R, N, D> {
+    /// Create a reader using a configured `NmeaParser`, yielding the messages
+    /// produced by its decoder
+    pub fn with_parser(reader: R, parser: NmeaParser<D>) -> Self {
+        NmeaReader {
+            reader,
+            stream: StreamParser::with_parser(parser),
+        }
+    }
+
+    /// Wait for the next complete message
+    ///
+    /// Reads from the underlying reader until a complete sentence is buffered.
+    /// Invalid sentences are reported as `ReadError::Parse`; reading can
+    /// continue after them.
+    pub async fn next_message(&mut self) -> Result<D::Message, ReadError<R::Error>> {
+        loop {
+            if let Some(result) = self.stream.next_message() {
+                return result.map_err(ReadError::Parse);
+            }
+
+            let read = self
+                .reader
+                .read(self.stream.spare_capacity())
+                .await
+                .map_err(ReadError::Io)?;
+            if read == 0 {
+                return Err(ReadError::Eof);
+            }
+            self.stream.commit(read);
+        }
+    }
+
+    /// Consume the adapter, returning the underlying reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::MessageType;
+    use core::future::Future;
+    use core::pin::pin;
+    use core::task::{Context, Poll, Waker};
+
+    /// Poll a future that never waits to completion
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn test_reader_messages_and_eof() {
+        let data: &[u8] = b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n\
+                            $GPGLL,4916.45,N,12311.12,W,225444,A,*00\r\n\
+                            $GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+        // Small buffer forces several reads per sentence
+        let mut reader = NmeaReader::<_, 80>::new(data);
+
+        block_on(async {
+            let msg = reader.next_message().await.unwrap();
+            assert_eq!(msg.message_type(), MessageType::GGA);
+            assert!(matches!(
+                reader.next_message().await.unwrap_err(),
+                ReadError::Parse(ParseError::InvalidChecksum(_))
+            ));
+            let msg = reader.next_message().await.unwrap();
+            assert_eq!(msg.message_type(), MessageType::GLL);
+            assert_eq!(reader.next_message().await.unwrap_err(), ReadError::Eof);
+        });
+    }
+
+    #[test]
+    fn test_reader_with_decoder() {
+        use crate::message::GenericSentence;
+        use crate::SentenceDecoder;
+
+        struct WaterTemperature;
+
+        impl SentenceDecoder for WaterTemperature {
+            type Output = f32;
+
+            fn accepts(&self, sentence: &GenericSentence) -> bool {
+                sentence.sentence_formatter() == "MTW"
+            }
+
+            fn decode(&self, sentence: &GenericSentence) -> Option<f32> {
+                sentence.field(0)?.parse().ok()
+            }
+        }
+
+        let data: &[u8] = b"$IIMTW,18.5,C*1F\r\n";
+        let parser = NmeaParser::builder().decoder(WaterTemperature).build();
+        let mut reader = NmeaReader::<_, 80, _>::with_parser(data, parser);
+
+        block_on(async {
+            let decoded = reader.next_message().await.unwrap();
+            assert_eq!(decoded.as_custom(), Some(&18.5));
+            assert_eq!(reader.next_message().await.unwrap_err(), ReadError::Eof);
+        });
+    }
+
+    #[test]
+    fn test_read_error_display() {
+        use core::error::Error;
+        use std::string::ToString;
+
+        let err: ReadError<u8> = ReadError::Parse(ParseError::Skipped);
+        assert_eq!(
+            err.to_string(),
+            "parse error: sentence skipped by parser filters"
+        );
+        assert!(err.source().is_some());
+        assert_eq!(ReadError::Io(5u8).to_string(), "read error: 5");
+        assert_eq!(ReadError::<u8>::Eof.to_string(), "end of input");
+    }
+}
@@ -0,0 +1,186 @@
+//! NMEA checksum helpers
+//!
+//! The checksum of a sentence is the XOR of all characters between the `$`
+//! start marker and the `*` checksum marker, transmitted as two hexadecimal
+//! digits after the `*`.
+//!
+//! These helpers let applications validate or generate checksums of raw
+//! sentences they forward without parsing them.
+//!
+//! # Example
+//!
+//! ```
+//! use rustedbytes_nmea::checksum;
+//! use rustedbytes_nmea::ChecksumPolicy;
+//!
+//! assert_eq!(checksum::compute(b"GPGLL,4916.45,N,12311.12,W,225444,A,"), 0x1D);
+//!
+//! let sentence = b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+//! assert!(checksum::verify(sentence, ChecksumPolicy::Strict).is_ok());
+//! ```
+
+use crate::scan;
+use crate::types::{ChecksumPolicy, ErrorDetail, ErrorReason};
+
+/// Compute the XOR checksum of a sentence body
+///
+/// `data` is the part of the sentence between `$` and `*`, e.g.
+/// `GPGLL,4916.45,N,12311.12,W,225444,A,`.
+pub fn compute(data: &[u8]) -> u8 {
+    scan::xor_bytes(data)
+}
+
+/// Decode a two-digit hexadecimal checksum
+///
+/// Both uppercase and lowercase digits are accepted. Returns `None` if
+/// `digits` is not exactly two hexadecimal digits.
+pub(crate) fn decode_hex(digits: &[u8]) -> Option<u8> {
+    if digits.len() != 2 {
+        return None;
+    }
+    Some(hex_digit(digits[0])? << 4 | hex_digit(digits[1])?)
+}
+
+fn hex_digit(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        _ => None,
+    }
+}
+
+/// Verify the checksum of a complete sentence starting with `$`
+///
+/// A trailing `<CR><LF>` is ignored. Succeeds if the sentence is acceptable
+/// under `policy`: a sentence without `*` checksum marker is only rejected by
+/// `ChecksumPolicy::Strict`, a wrong or malformed checksum is rejected unless
+/// the policy is `Off`. The error detail points at the `*` marker, or at the
+/// end of a sentence missing its checksum. A sentence not starting with `$`
+/// is rejected with `ErrorReason::MissingStart` at offset 0 under any policy.
+pub fn verify(sentence: &[u8], policy: ChecksumPolicy) -> Result<(), ErrorDetail> {
+    if sentence.first() != Some(&b'$') {
+        return Err(ErrorDetail::at(ErrorReason::MissingStart, 0));
+    }
+    if policy == ChecksumPolicy::Off {
+        return Ok(());
+    }
+    let end = sentence
+        .iter()
+        .rposition(|&b| b != b'\r' && b != b'\n')
+        .map_or(0, |last| last + 1);
+    let sentence = &sentence[..end];
+    match scan::find(sentence, b'*') {
+        Some(star) => match decode_hex(&sentence[star + 1..]) {
+            Some(expected) if expected == compute(&sentence[1..star]) => Ok(()),
+            Some(_) => Err(ErrorDetail::at(ErrorReason::ChecksumMismatch, star)),
+            None => Err(ErrorDetail::at(ErrorReason::MalformedChecksum, star)),
+        },
+        None if policy == ChecksumPolicy::Lenient => Ok(()),
+        None => Err(ErrorDetail::at(
+            ErrorReason::MissingChecksum,
+            sentence.len(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_checksum() {
+        assert_eq!(
+            compute(b"GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,"),
+            0x47
+        );
+        assert_eq!(compute(b""), 0);
+    }
+
+    #[test]
+    fn test_decode_hex() {
+        assert_eq!(decode_hex(b"47"), Some(0x47));
+        assert_eq!(decode_hex(b"7B"), Some(0x7B));
+        assert_eq!(decode_hex(b"4"), None);
+        assert_eq!(decode_hex(b"4G"), None);
+        assert_eq!(decode_hex(b"477"), None);
+    }
+
+    #[test]
+    fn test_decode_lowercase_hex() {
+        assert_eq!(decode_hex(b"7b"), Some(0x7B));
+        assert_eq!(decode_hex(b"aF"), Some(0xAF));
+    }
+
+    #[test]
+    fn test_verify_lenient() {
+        let policy = ChecksumPolicy::Lenient;
+        assert!(verify(
+            b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47",
+            policy
+        )
+        .is_ok());
+        assert_eq!(
+            verify(
+                b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*00",
+                policy
+            ),
+            Err(ErrorDetail::at(ErrorReason::ChecksumMismatch, 62))
+        );
+        assert_eq!(
+            verify(b"$GPGLL,4916.45,N,12311.12,W,225444,A,*", policy),
+            Err(ErrorDetail::at(ErrorReason::MalformedChecksum, 37))
+        );
+        assert!(verify(b"$GPGLL,4916.45,N,12311.12,W,225444,A,", policy).is_ok());
+    }
+
+    #[test]
+    fn test_verify_strict() {
+        let policy = ChecksumPolicy::Strict;
+        assert!(verify(b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D", policy).is_ok());
+        assert!(verify(b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1d", policy).is_ok());
+        assert!(verify(b"$GPGLL,4916.45,N,12311.12,W,225444,A,*00", policy).is_err());
+        assert_eq!(
+            verify(b"$GPGLL,4916.45,N,12311.12,W,225444,A,", policy),
+            Err(ErrorDetail::at(ErrorReason::MissingChecksum, 37))
+        );
+    }
+
+    #[test]
+    fn test_verify_with_line_ending() {
+        let policy = ChecksumPolicy::Strict;
+        assert!(verify(b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n", policy).is_ok());
+        assert!(verify(b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\n", policy).is_ok());
+        assert_eq!(
+            verify(b"$GPGLL,4916.45,N,12311.12,W,225444,A,\r\n", policy),
+            Err(ErrorDetail::at(ErrorReason::MissingChecksum, 37))
+        );
+    }
+
+    #[test]
+    fn test_verify_missing_start() {
+        let missing = Err(ErrorDetail::at(ErrorReason::MissingStart, 0));
+        for policy in [
+            ChecksumPolicy::Strict,
+            ChecksumPolicy::Lenient,
+            ChecksumPolicy::Off,
+        ] {
+            assert_eq!(verify(b"*00", policy), missing);
+            assert_eq!(verify(b"", policy), missing);
+            assert_eq!(verify(b"\r\n", policy), missing);
+            assert_eq!(
+                verify(b"GPGLL,4916.45,N,12311.12,W,225444,A,*1D", policy),
+                missing
+            );
+        }
+        assert!(verify(b"$*00", ChecksumPolicy::Strict).is_ok());
+    }
+
+    #[test]
+    fn test_verify_off() {
+        let policy = ChecksumPolicy::Off;
+        assert!(verify(b"$GPGLL,4916.45,N,12311.12,W,225444,A,*00", policy).is_ok());
+        assert!(verify(b"$GPGLL,4916.45,N,12311.12,W,225444,A,*", policy).is_ok());
+        assert!(verify(b"$GPGLL,4916.45,N,12311.12,W,225444,A,", policy).is_ok());
+    }
+}
@@ -0,0 +1,85 @@
+//! Reception timestamps
+//!
+//! NMEA sentences only carry the UTC time of the fix, not when they were
+//! received. For latency measurement or for aligning NMEA data with other
+//! sensors, messages can be wrapped in a [`Timestamped`] carrying a local
+//! reception time in milliseconds, either passed explicitly to
+//! [`NmeaParser::parse_bytes_with_timestamp`](crate::NmeaParser::parse_bytes_with_timestamp)
+//! or read from a [`Clock`] by
+//! [`StreamParser::next_message_with_clock`](crate::StreamParser::next_message_with_clock).
+
+/// Source of local timestamps in milliseconds (e.g. a monotonic system tick)
+///
+/// Implemented for closures returning `u64`, so `&|| ticks_ms()` can be
+/// passed wherever a clock is expected.
+pub trait Clock {
+    /// Current local time in milliseconds
+    fn now_ms(&self) -> u64;
+}
+
+impl<F: Fn() -> u64> Clock for F {
+    fn now_ms(&self) -> u64 {
+        self()
+    }
+}
+
+/// Message with the local time at which it was received
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Timestamped<T> {
+    pub message: T,
+    /// Local reception time in milliseconds, as given by the caller or `Clock`
+    pub timestamp_ms: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use crate::types::MessageType;
+    use crate::{NmeaParser, StreamParser};
+
+    #[test]
+    fn test_parse_bytes_with_timestamp() {
+        let parser = NmeaParser::new();
+        let data = b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+
+        let (msg, consumed) = parser.parse_bytes_with_timestamp(data, 1_500).unwrap();
+        let msg = msg.unwrap();
+        assert_eq!(msg.timestamp_ms, 1_500);
+        assert_eq!(msg.message.message_type(), MessageType::GLL);
+        assert_eq!(consumed, data.len());
+
+        let (msg, consumed) = parser
+            .parse_bytes_with_timestamp(&data[..20], 1_600)
+            .unwrap();
+        assert!(msg.is_none());
+        assert_eq!(consumed, 0);
+    }
+
+    #[test]
+    fn test_stream_with_clock() {
+        let ticks = Cell::new(100);
+        let clock = || {
+            ticks.set(ticks.get() + 10);
+            ticks.get()
+        };
+
+        let mut stream = StreamParser::<256>::new();
+        stream.feed(
+            b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n\
+              $GPGLL,4916.45,N,12311.12,W,225444,A,*00\r\n\
+              $GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n",
+        );
+
+        let gga = stream.next_message_with_clock(&clock).unwrap().unwrap();
+        assert_eq!(gga.message.message_type(), MessageType::GGA);
+        assert_eq!(gga.timestamp_ms, 110);
+        assert!(stream.next_message_with_clock(&clock).unwrap().is_err());
+        let gll = stream.next_message_with_clock(&clock).unwrap().unwrap();
+        assert_eq!(gll.message.message_type(), MessageType::GLL);
+        assert_eq!(gll.timestamp_ms, 120);
+        assert!(stream.next_message_with_clock(&clock).is_none());
+    }
+}
@@ -0,0 +1,186 @@
+//! Latitude and longitude representation
+//!
+//! Coordinates are stored as `f64` holding the raw NMEA value in `ddmm.mmmm`
+//! (latitude) or `dddmm.mmmm` (longitude) format. The value is always
+//! unsigned: the hemisphere is kept in the separate direction field.
+//!
+//! [`Degrees`] holds a coordinate converted to signed decimal degrees, combining
+//! the value with its hemisphere.
+//!
+//! The `fixed-point` feature adds `latitude_fixed()` / `longitude_fixed()`
+//! accessors returning signed `i32` values in units of 1e-7 degrees (e.g.
+//! `4807.038` N becomes `481_173_000`, i.e. 48.1173°). The feature is
+//! additive: the `f64` fields and their validation are the same with and
+//! without it.
+
+/// Latitude/longitude value in raw `ddmm.mmmm` format
+pub type Coordinate = f64;
+
+/// Signed decimal degrees, negative south of the equator and west of Greenwich
+pub type Degrees = f64;
+
+/// Number of fixed-point coordinate units per degree
+#[cfg(feature = "fixed-point")]
+pub const COORDINATE_SCALE: i32 = 10_000_000;
+
+/// Parse a coordinate field in `ddmm.mmmm` / `dddmm.mmmm` format
+///
+/// Returns `None` unless the field is plain digits with an optional fraction,
+/// with minutes below 60 and at most 180 degrees.
+pub(crate) fn parse(value: &str) -> Option<Coordinate> {
+    let bytes = value.as_bytes();
+    let dot = crate::scan::find(bytes, b'.').unwrap_or(bytes.len());
+    let (int_part, frac_part) = (&bytes[..dot], bytes.get(dot + 1..).unwrap_or(&[]));
+
+    if int_part.is_empty() || int_part.len() > 5 {
+        return None;
+    }
+    if !int_part.iter().chain(frac_part).all(u8::is_ascii_digit) {
+        return None;
+    }
+
+    let whole = int_part
+        .iter()
+        .fold(0u32, |acc, &b| acc * 10 + u32::from(b - b'0'));
+    if whole % 100 >= 60 || whole / 100 > 180 {
+        return None;
+    }
+
+    crate::number::parse(value)
+}
+
+/// Convert a latitude and its `N`/`S` direction to signed decimal degrees
+pub(crate) fn latitude_degrees(value: Coordinate, direction: char) -> Option<Degrees> {
+    signed(to_degrees(value), direction, 'N', 'S')
+}
+
+/// Convert a longitude and its `E`/`W` direction to signed decimal degrees
+pub(crate) fn longitude_degrees(value: Coordinate, direction: char) -> Option<Degrees> {
+    signed(to_degrees(value), direction, 'E', 'W')
+}
+
+fn signed(degrees: Degrees, direction: char, positive: char, negative: char) -> Option<Degrees> {
+    match direction {
+        d if d == positive => Some(degrees),
+        d if d == negative => Some(-degrees),
+        _ => None,
+    }
+}
+
+/// Split signed decimal degrees into a coordinate and its direction
+/// (`positive` or `negative` hemisphere character)
+pub(crate) fn from_degrees(degrees: Degrees, positive: char, negative: char) -> (Coordinate, char) {
+    let direction = if degrees < 0.0 { negative } else { positive };
+    let degrees = degrees.abs();
+    let whole = degrees as i64 as f64;
+    (whole * 100.0 + (degrees - whole) * 60.0, direction)
+}
+
+fn to_degrees(value: Coordinate) -> Degrees {
+    let degrees = (value / 100.0) as i64 as f64;
+    degrees + (value - degrees * 100.0) / 60.0
+}
+
+/// Convert signed decimal degrees to units of 1e-7 degrees, rounded to nearest
+#[cfg(feature = "fixed-point")]
+pub(crate) fn to_fixed(degrees: Degrees) -> i32 {
+    let scaled = degrees * COORDINATE_SCALE as f64;
+    let rounded = if scaled < 0.0 {
+        scaled - 0.5
+    } else {
+        scaled + 0.5
+    };
+    rounded as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decimal_degrees() {
+        let lat = latitude_degrees(4807.038, 'N').unwrap();
+        assert!((lat - 48.1173).abs() < 1e-9);
+        let lon = longitude_degrees(1131.000, 'W').unwrap();
+        assert!((lon + 11.516_666_666).abs() < 1e-9);
+        let lat = latitude_degrees(3723.46587, 'S').unwrap();
+        assert!((lat + 37.391_097_833).abs() < 1e-9);
+        assert_eq!(longitude_degrees(0.0, 'E'), Some(0.0));
+    }
+
+    #[test]
+    fn test_decimal_degrees_invalid_direction() {
+        let value = parse("4807.038").unwrap();
+        assert_eq!(latitude_degrees(value, 'E'), None);
+        assert_eq!(longitude_degrees(value, 'N'), None);
+        assert_eq!(latitude_degrees(value, 'n'), None);
+    }
+
+    #[test]
+    fn test_from_degrees_round_trip() {
+        for (value, direction) in [("4807.038", 'N'), ("3352.128", 'S'), ("0000.000", 'N')] {
+            let degrees = latitude_degrees(parse(value).unwrap(), direction).unwrap();
+            let (coordinate, back) = from_degrees(degrees, 'N', 'S');
+            assert_eq!(back, direction);
+            assert_eq!(latitude_degrees(coordinate, back), Some(degrees));
+        }
+
+        let degrees = longitude_degrees(parse("12311.12").unwrap(), 'W').unwrap();
+        let (coordinate, direction) = from_degrees(degrees, 'E', 'W');
+        assert_eq!(direction, 'W');
+        assert!((coordinate - 12311.12).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_parse_coordinates() {
+        assert_eq!(parse("4807.038"), Some(4807.038));
+        assert_eq!(parse("01131.000"), Some(1131.0));
+        assert_eq!(parse("18000.0000"), Some(18000.0));
+        assert_eq!(parse("4807"), Some(4807.0));
+        assert_eq!(parse("4807.0380009"), Some(4807.0380009));
+    }
+
+    #[test]
+    fn test_parse_invalid_coordinates() {
+        for value in [
+            "",
+            "48a7.038",
+            "4860.000",
+            "18100.000",
+            "-4807.038",
+            "+4807.038",
+            ".5",
+            "4807.0x",
+            "123456.0",
+            "1e3",
+            "inf",
+        ] {
+            assert_eq!(parse(value), None, "{value}");
+        }
+    }
+
+    #[cfg(feature = "fixed-point")]
+    #[test]
+    fn test_fixed_point_matches_floating_point() {
+        // The same fields go through both representations
+        let cases = [
+            ("4807.038", 'N', 481_173_000),
+            ("01131.000", 'W', -115_166_667),
+            ("3723.46587", 'S', -373_910_978),
+            ("9000.0000", 'N', 900_000_000),
+            ("18000.0000", 'E', 1_800_000_000),
+            ("0000.000", 'S', 0),
+            ("4807", 'N', 481_166_667),
+        ];
+        for (value, direction, fixed) in cases {
+            let coordinate = parse(value).unwrap();
+            let degrees = match direction {
+                'N' | 'S' => latitude_degrees(coordinate, direction),
+                _ => longitude_degrees(coordinate, direction),
+            }
+            .unwrap();
+            assert_eq!(to_fixed(degrees), fixed, "{value}");
+            assert!((f64::from(fixed) / f64::from(COORDINATE_SCALE) - degrees).abs() < 1e-7);
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! UTC time and date fields
+//!
+//! NMEA transmits the time of day as `hhmmss[.sss]` and the date as `ddmmyy`.
+//! Both are validated and decoded when a message is parsed, so applications
+//! never have to slice the raw strings.
+
+use core::fmt;
+
+/// UTC time of day
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct NmeaTime {
+    pub hour: u8,
+    pub minute: u8,
+    /// Seconds, 60 during a leap second
+    pub second: u8,
+    /// Fraction of the second in milliseconds (digits beyond the third are truncated)
+    pub millis: u16,
+}
+
+/// Calendar date
+///
+/// Some receivers send `000000` until they have determined the date; day and
+/// month are then 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct NmeaDate {
+    /// Four-digit year: two-digit years 80-99 map to 1980-1999, 00-79 to 2000-2079
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl NmeaTime {
+    /// Parse an `hhmmss[.sss]` field
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        let bytes = value.as_bytes();
+        if bytes.len() < 6 || (bytes.len() > 6 && bytes[6] != b'.') {
+            return None;
+        }
+
+        let mut millis: u16 = 0;
+        let mut scale: u16 = 1000;
+        for &b in bytes.get(7..).unwrap_or(&[]) {
+            let d = u16::from(digit(b)?);
+            if scale > 1 {
+                scale /= 10;
+                millis += d * scale;
+            }
+        }
+
+        let time = NmeaTime {
+            hour: two_digits(&bytes[0..2])?,
+            minute: two_digits(&bytes[2..4])?,
+            second: two_digits(&bytes[4..6])?,
+            millis,
+        };
+        (time.hour < 24 && time.minute < 60 && time.second <= 60).then_some(time)
+    }
+}
+
+impl NmeaDate {
+    /// Parse a `ddmmyy` field
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        let bytes = value.as_bytes();
+        if bytes.len() != 6 {
+            return None;
+        }
+
+        let yy = u16::from(two_digits(&bytes[4..6])?);
+        let date = NmeaDate {
+            year: if yy >= 80 { 1900 + yy } else { 2000 + yy },
+            month: two_digits(&bytes[2..4])?,
+            day: two_digits(&bytes[0..2])?,
+        };
+        (date.month <= 12 && date.day <= 31).then_some(date)
+    }
+}
+
+/// Formats as `hh:mm:ss`, followed by `.sss` when the fraction is not zero
+impl fmt::Display for NmeaTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)?;
+        if self.millis != 0 {
+            write!(f, ".{:03}", self.millis)?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats as ISO 8601 `yyyy-mm-dd`
+impl fmt::Display for NmeaDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+fn digit(b: u8) -> Option<u8> {
+    b.is_ascii_digit().then(|| b - b'0')
+}
+
+fn two_digits(bytes: &[u8]) -> Option<u8> {
+    Some(digit(bytes[0])? * 10 + digit(bytes[1])?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_time() {
+        assert_eq!(
+            NmeaTime::parse("123519"),
+            Some(NmeaTime {
+                hour: 12,
+                minute: 35,
+                second: 19,
+                millis: 0
+            })
+        );
+        assert_eq!(NmeaTime::parse("225444.50").map(|t| t.millis), Some(500));
+        assert_eq!(NmeaTime::parse("122310.0").map(|t| t.millis), Some(0));
+        assert_eq!(NmeaTime::parse("000000.1234").map(|t| t.millis), Some(123));
+        assert_eq!(NmeaTime::parse("235960").map(|t| t.second), Some(60));
+    }
+
+    #[test]
+    fn test_parse_time_invalid() {
+        for input in [
+            "", "12351", "1235a9", "243519", "126019", "123561", "123519,5", "123519.x",
+        ] {
+            assert_eq!(NmeaTime::parse(input), None, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_parse_date() {
+        assert_eq!(
+            NmeaDate::parse("230394"),
+            Some(NmeaDate {
+                year: 1994,
+                month: 3,
+                day: 23
+            })
+        );
+        assert_eq!(NmeaDate::parse("010125").map(|d| d.year), Some(2025));
+        assert_eq!(NmeaDate::parse("311279").map(|d| d.year), Some(2079));
+        assert_eq!(NmeaDate::parse("010180").map(|d| d.year), Some(1980));
+        assert_eq!(
+            NmeaDate::parse("000000").map(|d| (d.day, d.month)),
+            Some((0, 0))
+        );
+    }
+
+    #[test]
+    fn test_parse_date_invalid() {
+        for input in ["", "23039", "2303944", "321394", "231394", "2303a4"] {
+            assert_eq!(NmeaDate::parse(input), None, "{input:?}");
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! User-extensible sentence decoding
+//!
+//! The built-in message types are a closed set. Downstream crates implement
+//! [`SentenceDecoder`] for proprietary or niche sentences and register it with
+//! [`NmeaParserBuilder::decoder`](crate::NmeaParserBuilder::decoder). The
+//! parser then offers sentences not decoded by the library (unknown and
+//! proprietary types) to the decoder before falling back to
+//! `NmeaMessage::Unknown` or `NmeaMessage::Proprietary`, in `parse_bytes`,
+//! `iter`, `StreamParser` and the readers alike: their messages become
+//! [`Decoded`] values.
+//!
+//! Several decoders with the same output type can be combined as a tuple
+//! `(first, second)`; the first one accepting a sentence decodes it.
+
+use crate::message::GenericSentence;
+use crate::types::{ErrorDetail, ErrorReason, NmeaMessage, ParseError};
+
+/// Decoder for sentence types not supported by the library
+pub trait SentenceDecoder {
+    /// Decoded representation of the custom sentences
+    type Output;
+
+    /// Tell whether this decoder handles the sentence (usually by checking
+    /// `GenericSentence::sentence_formatter()` or `address()`)
+    fn accepts(&self, sentence: &GenericSentence) -> bool;
+
+    /// Decode an accepted sentence
+    ///
+    /// Returning `None` reports the sentence as `ParseError::InvalidMessage`
+    /// with `ErrorReason::DecoderFailed`, e.g. when a mandatory field is missing.
+    fn decode(&self, sentence: &GenericSentence) -> Option<Self::Output>;
+}
+
+impl<A, B> SentenceDecoder for (A, B)
+where
+    A: SentenceDecoder,
+    B: SentenceDecoder<Output = A::Output>,
+{
+    type Output = A::Output;
+
+    fn accepts(&self, sentence: &GenericSentence) -> bool {
+        self.0.accepts(sentence) || self.1.accepts(sentence)
+    }
+
+    fn decode(&self, sentence: &GenericSentence) -> Option<Self::Output> {
+        if self.0.accepts(sentence) {
+            self.0.decode(sentence)
+        } else {
+            self.1.decode(sentence)
+        }
+    }
+}
+
+/// Decoder configuration of a parser without custom decoder
+///
+/// Parsers using it return plain `NmeaMessage`s.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NoDecoder;
+
+/// Decoder configuration of a `NmeaParser`, selecting the message type it returns
+///
+/// Implemented by `NoDecoder`, which returns `NmeaMessage`, and by every
+/// `SentenceDecoder`, which returns `Decoded<Output>`. This trait is sealed
+/// and cannot be implemented outside the crate.
+pub trait MessageDecoder: private::Sealed {
+    /// Message returned by the parser
+    type Message;
+
+    #[doc(hidden)]
+    fn decode_message(&self, msg: NmeaMessage) -> Result<Self::Message, ParseError>;
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for super::NoDecoder {}
+    impl<D: super::SentenceDecoder> Sealed for D {}
+}
+
+impl MessageDecoder for NoDecoder {
+    type Message = NmeaMessage;
+
+    fn decode_message(&self, msg: NmeaMessage) -> Result<NmeaMessage, ParseError> {
+        Ok(msg)
+    }
+}
+
+impl<D: SentenceDecoder> MessageDecoder for D {
+    type Message = Decoded<D::Output>;
+
+    /// Offer a parsed message to the decoder if it is a sentence not decoded by the library
+    fn decode_message(&self, msg: NmeaMessage) -> Result<Self::Message, ParseError> {
+        match msg.as_generic() {
+            Some(raw) if self.accepts(raw) => {
+                self.decode(raw)
+                    .map(Decoded::Custom)
+                    .ok_or(ParseError::InvalidMessage(ErrorDetail::at(
+                        ErrorReason::DecoderFailed,
+                        0,
+                    )))
+            }
+            _ => Ok(Decoded::Builtin(msg)),
+        }
+    }
+}
+
+/// Message decoded either by the library or by a custom `SentenceDecoder`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Decoded<T> {
+    /// Message decoded by the library (including `NmeaMessage::Unknown`)
+    Builtin(NmeaMessage),
+    /// Message decoded by the custom decoder
+    Custom(T),
+}
+
+impl<T> Decoded<T> {
+    /// Get the built-in message, if the sentence was decoded by the library
+    pub fn as_builtin(&self) -> Option<&NmeaMessage> {
+        if let Decoded::Builtin(msg) = self {
+            Some(msg)
+        } else {
+            None
+        }
+    }
+
+    /// Get the custom message, if the sentence was decoded by the custom decoder
+    pub fn as_custom(&self) -> Option<&T> {
+        if let Decoded::Custom(data) = self {
+            Some(data)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ErrorReason, MessageType, TalkerId};
+    use crate::NmeaParser;
+
+    #[derive(Debug, PartialEq)]
+    enum Custom {
+        WaterTemperature(f32),
+        CrossTrackError(f32),
+    }
+
+    struct MtwDecoder;
+
+    impl SentenceDecoder for MtwDecoder {
+        type Output = Custom;
+
+        fn accepts(&self, sentence: &GenericSentence) -> bool {
+            sentence.sentence_formatter() == "MTW"
+        }
+
+        fn decode(&self, sentence: &GenericSentence) -> Option<Custom> {
+            Some(Custom::WaterTemperature(sentence.field(0)?.parse().ok()?))
+        }
+    }
+
+    struct XteDecoder;
+
+    impl SentenceDecoder for XteDecoder {
+        type Output = Custom;
+
+        fn accepts(&self, sentence: &GenericSentence) -> bool {
+            sentence.sentence_formatter() == "XTE"
+        }
+
+        fn decode(&self, sentence: &GenericSentence) -> Option<Custom> {
+            Some(Custom::CrossTrackError(sentence.field(2)?.parse().ok()?))
+        }
+    }
+
+    #[test]
+    fn test_custom_decoder() {
+        let parser = NmeaParser::builder().decoder(MtwDecoder).build();
+        let sentence = b"$IIMTW,18.5,C\r\n";
+
+        let (decoded, consumed) = parser.parse_bytes(sentence).unwrap();
+        assert_eq!(consumed, sentence.len());
+        assert_eq!(
+            decoded.unwrap().as_custom(),
+            Some(&Custom::WaterTemperature(18.5))
+        );
+    }
+
+    #[test]
+    fn test_builtin_and_unknown_fallback() {
+        let parser = NmeaParser::builder().decoder(MtwDecoder).build();
+
+        let gll = b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+        let (decoded, _) = parser.parse_bytes(gll).unwrap();
+        let decoded = decoded.unwrap();
+        assert_eq!(
+            decoded.as_builtin().unwrap().message_type(),
+            MessageType::GLL
+        );
+
+        let xte = b"$GPXTE,A,A,0.67,L,N*6F\r\n";
+        let (decoded, _) = parser.parse_bytes(xte).unwrap();
+        let msg = decoded.unwrap();
+        assert_eq!(msg.as_builtin().unwrap().talker_id(), TalkerId::GP);
+        assert!(msg.as_builtin().unwrap().as_unknown().is_some());
+    }
+
+    #[test]
+    fn test_combined_decoders() {
+        let parser = NmeaParser::builder()
+            .decoder((MtwDecoder, XteDecoder))
+            .build();
+
+        let xte = b"$GPXTE,A,A,0.67,L,N*6F\r\n";
+        let (decoded, _) = parser.parse_bytes(xte).unwrap();
+        assert_eq!(
+            decoded.unwrap().as_custom(),
+            Some(&Custom::CrossTrackError(0.67))
+        );
+    }
+
+    #[test]
+    fn test_custom_decoder_for_proprietary_sentence() {
+        struct GarminError;
+
+        impl SentenceDecoder for GarminError {
+            type Output = f32;
+
+            fn accepts(&self, sentence: &GenericSentence) -> bool {
+                sentence.address() == "PGRME"
+            }
+
+            fn decode(&self, sentence: &GenericSentence) -> Option<f32> {
+                sentence.field(0)?.parse().ok()
+            }
+        }
+
+        let parser = NmeaParser::builder().decoder(GarminError).build();
+        let sentence = b"$PGRME,15.0,M,45.0,M,25.0,M*1C\r\n";
+
+        let (decoded, _) = parser.parse_bytes(sentence).unwrap();
+        assert_eq!(decoded.unwrap().as_custom(), Some(&15.0));
+    }
+
+    #[test]
+    fn test_custom_decoder_rejects_sentence() {
+        let parser = NmeaParser::builder().decoder(MtwDecoder).build();
+        let sentence = b"$IIMTW,,C\r\n";
+
+        let (err, consumed) = parser.parse_bytes(sentence).unwrap_err();
+        assert_eq!(err.reason(), Some(ErrorReason::DecoderFailed));
+        assert_eq!(consumed, sentence.len());
+    }
+}
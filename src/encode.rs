@@ -0,0 +1,247 @@
+//! Sentence encoding
+//!
+//! Parsed messages can be turned back into NMEA sentences with the `encode`
+//! method of [`NmeaMessage`](crate::NmeaMessage) and of each data structure.
+//! The output starts with `$`, ends with the `*hh` checksum and `<CR><LF>`,
+//! and uses the usual fixed-width NMEA field formats (zero-padded coordinates,
+//! `hhmmss.ss` times, two-digit satellite counts, ...). Missing optional
+//! fields are left empty.
+
+use core::fmt::{self, Write};
+
+use crate::checksum;
+use crate::coordinate::Coordinate;
+use crate::datetime::{NmeaDate, NmeaTime};
+use crate::types::TalkerId;
+
+/// Error returned when a sentence cannot be encoded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum EncodeError {
+    /// The output buffer cannot hold the complete sentence
+    BufferTooSmall,
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::BufferTooSmall => f.write_str("output buffer too small for sentence"),
+        }
+    }
+}
+
+impl core::error::Error for EncodeError {}
+
+/// Writer appending the fields of a sentence to a caller-provided buffer
+pub(crate) struct SentenceWriter<'a> {
+    buffer: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> SentenceWriter<'a> {
+    /// Start a sentence with `$` followed by the talker ID and formatter
+    pub(crate) fn new(
+        buffer: &'a mut [u8],
+        talker_id: TalkerId,
+        formatter: &str,
+    ) -> Result<Self, EncodeError> {
+        let mut writer = SentenceWriter { buffer, len: 0 };
+        writer.push(b"$")?;
+        writer.push(talker_id.as_str().as_bytes())?;
+        writer.push(formatter.as_bytes())?;
+        Ok(writer)
+    }
+
+    /// Start a sentence from raw content (without `$`, checksum and line ending)
+    pub(crate) fn raw(buffer: &'a mut [u8], content: &[u8]) -> Result<Self, EncodeError> {
+        let mut writer = SentenceWriter { buffer, len: 0 };
+        writer.push(b"$")?;
+        writer.push(content)?;
+        Ok(writer)
+    }
+
+    /// Append raw bytes to the current field
+    pub(crate) fn push(&mut self, bytes: &[u8]) -> Result<(), EncodeError> {
+        let end = self.len + bytes.len();
+        self.buffer
+            .get_mut(self.len..end)
+            .ok_or(EncodeError::BufferTooSmall)?
+            .copy_from_slice(bytes);
+        self.len = end;
+        Ok(())
+    }
+
+    /// Append a field formatted with `Display`, or an empty field for `None`
+    pub(crate) fn field<T: fmt::Display>(&mut self, value: Option<T>) -> Result<(), EncodeError> {
+        self.push(b",")?;
+        match value {
+            Some(value) => self.write_arg(format_args!("{}", value)),
+            None => Ok(()),
+        }
+    }
+
+    /// Append a field formatted with explicit arguments
+    pub(crate) fn field_fmt(&mut self, args: fmt::Arguments<'_>) -> Result<(), EncodeError> {
+        self.push(b",")?;
+        self.write_arg(args)
+    }
+
+    /// Append a field formatted with `{:0width$}`, or an empty field for `None`
+    pub(crate) fn field_padded<T: fmt::Display>(
+        &mut self,
+        value: Option<T>,
+        width: usize,
+    ) -> Result<(), EncodeError> {
+        match value {
+            Some(value) => self.field_fmt(format_args!("{:0width$}", value, width = width)),
+            None => self.field::<T>(None),
+        }
+    }
+
+    /// Append a `hhmmss[.ss]` UTC time field
+    pub(crate) fn time(&mut self, time: NmeaTime) -> Result<(), EncodeError> {
+        self.field_fmt(format_args!(
+            "{:02}{:02}{:02}",
+            time.hour, time.minute, time.second
+        ))?;
+        match time.millis {
+            0 => Ok(()),
+            ms if ms % 10 == 0 => self.write_arg(format_args!(".{:02}", ms / 10)),
+            ms => self.write_arg(format_args!(".{:03}", ms)),
+        }
+    }
+
+    /// Append a `ddmmyy` date field
+    pub(crate) fn date(&mut self, date: NmeaDate) -> Result<(), EncodeError> {
+        self.field_fmt(format_args!(
+            "{:02}{:02}{:02}",
+            date.day,
+            date.month,
+            date.year % 100
+        ))
+    }
+
+    /// Append a coordinate field as `d..dmm.mmmmm` with `degree_digits`
+    /// zero-padded degree digits (2 for latitude, 3 for longitude)
+    pub(crate) fn coordinate(
+        &mut self,
+        value: Coordinate,
+        degree_digits: usize,
+    ) -> Result<(), EncodeError> {
+        self.push(b",")?;
+        self.write_coordinate(value, degree_digits)
+    }
+
+    fn write_coordinate(&mut self, value: f64, degree_digits: usize) -> Result<(), EncodeError> {
+        let value = value.abs();
+        let mut degrees = (value / 100.0) as u64;
+        // Minutes with 5 decimals, rounded; 60.00000 carries into the degrees
+        let mut minutes = ((value - (degrees * 100) as f64) * 100_000.0 + 0.5) as u64;
+        if minutes >= 6_000_000 {
+            degrees += 1;
+            minutes -= 6_000_000;
+        }
+        self.write_arg(format_args!(
+            "{:0width$}{:02}.{:05}",
+            degrees,
+            minutes / 100_000,
+            minutes % 100_000,
+            width = degree_digits
+        ))
+    }
+
+    fn write_arg(&mut self, args: fmt::Arguments<'_>) -> Result<(), EncodeError> {
+        self.write_fmt(args)
+            .map_err(|_| EncodeError::BufferTooSmall)
+    }
+
+    /// Append the checksum and line ending, returning the sentence length
+    pub(crate) fn finish(mut self) -> Result<usize, EncodeError> {
+        let checksum = checksum::compute(&self.buffer[1..self.len]);
+        self.write_arg(format_args!("*{:02X}\r\n", checksum))?;
+        Ok(self.len)
+    }
+}
+
+impl Write for SentenceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push(s.as_bytes()).map_err(|_| fmt::Error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_writer_appends_checksum() {
+        let mut buffer = [0u8; 64];
+        let mut writer = SentenceWriter::new(&mut buffer, TalkerId::GP, "GLL").unwrap();
+        writer.field(Some("4916.45")).unwrap();
+        writer.field(Some('N')).unwrap();
+        writer.field::<u8>(None).unwrap();
+        let len = writer.finish().unwrap();
+
+        assert_eq!(&buffer[..len], b"$GPGLL,4916.45,N,*17\r\n");
+    }
+
+    #[test]
+    fn test_writer_buffer_too_small() {
+        let mut buffer = [0u8; 10];
+        let mut writer = SentenceWriter::new(&mut buffer, TalkerId::GP, "GLL").unwrap();
+        assert_eq!(
+            writer.field(Some("4916.45")),
+            Err(EncodeError::BufferTooSmall)
+        );
+
+        let mut buffer = [0u8; 8];
+        let writer = SentenceWriter::new(&mut buffer, TalkerId::GP, "GLL").unwrap();
+        assert_eq!(writer.finish(), Err(EncodeError::BufferTooSmall));
+    }
+
+    #[test]
+    fn test_writer_time_and_date() {
+        let mut buffer = [0u8; 64];
+        let mut writer = SentenceWriter::new(&mut buffer, TalkerId::GP, "ZDA").unwrap();
+        writer.time(NmeaTime::parse("225444.50").unwrap()).unwrap();
+        writer.time(NmeaTime::parse("123519").unwrap()).unwrap();
+        writer.time(NmeaTime::parse("000000.125").unwrap()).unwrap();
+        writer.date(NmeaDate::parse("230394").unwrap()).unwrap();
+        let len = writer.finish().unwrap();
+
+        assert!(buffer[..len].starts_with(b"$GPZDA,225444.50,123519,000000.125,230394*"));
+    }
+
+    #[test]
+    fn test_writer_coordinates() {
+        let mut buffer = [0u8; 64];
+        let mut writer = SentenceWriter::new(&mut buffer, TalkerId::GP, "GLL").unwrap();
+        writer.coordinate(4807.038, 2).unwrap();
+        writer.coordinate(1131.0, 3).unwrap();
+        writer.coordinate(5.5, 2).unwrap();
+        let len = writer.finish().unwrap();
+
+        assert!(buffer[..len].starts_with(b"$GPGLL,4807.03800,01131.00000,0005.50000*"));
+    }
+
+    #[test]
+    fn test_writer_coordinate_minutes_carry() {
+        let mut buffer = [0u8; 64];
+        let mut writer = SentenceWriter::new(&mut buffer, TalkerId::GP, "GLL").unwrap();
+        writer.coordinate(4859.9999994, 2).unwrap();
+        writer.coordinate(17959.999996, 3).unwrap();
+        writer.coordinate(4859.999994, 2).unwrap();
+        let len = writer.finish().unwrap();
+
+        assert!(buffer[..len].starts_with(b"$GPGLL,4900.00000,18000.00000,4859.99999*"));
+    }
+
+    #[test]
+    fn test_encode_error_display() {
+        use std::string::ToString;
+
+        let err: &dyn core::error::Error = &EncodeError::BufferTooSmall;
+        assert_eq!(err.to_string(), "output buffer too small for sentence");
+    }
+}
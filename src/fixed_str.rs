@@ -0,0 +1,67 @@
+//! Fixed-capacity string storage
+//!
+//! Text fields are copied out of the sentence into a [`FixedStr`] so parsed
+//! messages do not borrow the input buffer. Values longer than the capacity
+//! are truncated.
+
+use core::fmt;
+
+/// Inline string of at most `N` bytes
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) struct FixedStr<const N: usize> {
+    data: [u8; N],
+    len: u8,
+}
+
+impl<const N: usize> FixedStr<N> {
+    /// Copy a value, truncated to `N` bytes
+    pub(crate) fn new(value: &[u8]) -> Self {
+        let len = value.len().min(N);
+        let mut data = [0u8; N];
+        data[..len].copy_from_slice(&value[..len]);
+        FixedStr {
+            data,
+            len: len as u8,
+        }
+    }
+
+    pub(crate) fn as_bytes(&self) -> &[u8] {
+        &self.data[..self.len as usize]
+    }
+
+    /// Get the value as string slice (empty if not valid UTF-8)
+    pub(crate) fn as_str(&self) -> &str {
+        core::str::from_utf8(self.as_bytes()).unwrap_or("")
+    }
+
+    /// Get the value as string slice, `None` if empty
+    pub(crate) fn as_opt_str(&self) -> Option<&str> {
+        Some(self.as_str()).filter(|s| !s.is_empty())
+    }
+}
+
+impl<const N: usize> fmt::Debug for FixedStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<const N: usize> defmt::Format for FixedStr<N> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{=str}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_str_truncates() {
+        let value = FixedStr::<4>::new(b"012345");
+        assert_eq!(value.as_str(), "0123");
+        assert_eq!(value.as_opt_str(), Some("0123"));
+        assert_eq!(FixedStr::<4>::new(b"").as_opt_str(), None);
+    }
+}
@@ -0,0 +1,183 @@
+//! Callback-based message dispatch
+//!
+//! Implement [`MessageHandler`] for the message types of interest and pass
+//! the handler to [`NmeaParser::parse_into`](crate::NmeaParser::parse_into).
+//! All methods have empty default implementations, so only the relevant
+//! ones need to be written.
+
+use crate::message::{
+    GgaData, GllData, GnsData, GsaData, GsvData, ProprietaryData, QueryData, RmcData, VtgData,
+};
+use crate::types::{MessageType, NmeaMessage, ParseError};
+
+/// Receiver of parsed messages, one method per message type
+pub trait MessageHandler {
+    /// Tell whether sentences of the given type should be decoded
+    ///
+    /// Returning `false` skips field extraction and the construction of the
+    /// data structure for that type; `MessageType::Unknown` controls the calls
+    /// to `on_unknown`. The default accepts every type.
+    fn wants(&self, _message_type: MessageType) -> bool {
+        true
+    }
+
+    /// Called for each decoded GGA sentence
+    fn on_gga(&mut self, _data: &GgaData) {}
+
+    /// Called for each decoded RMC sentence
+    fn on_rmc(&mut self, _data: &RmcData) {}
+
+    /// Called for each decoded GSA sentence
+    fn on_gsa(&mut self, _data: &GsaData) {}
+
+    /// Called for each decoded GSV sentence
+    fn on_gsv(&mut self, _data: &GsvData) {}
+
+    /// Called for each decoded GLL sentence
+    fn on_gll(&mut self, _data: &GllData) {}
+
+    /// Called for each decoded VTG sentence
+    fn on_vtg(&mut self, _data: &VtgData) {}
+
+    /// Called for each decoded GNS sentence
+    fn on_gns(&mut self, _data: &GnsData) {}
+
+    /// Called for each decoded query sentence
+    fn on_query(&mut self, _data: &QueryData) {}
+
+    /// Called for each proprietary (`$P...`) sentence
+    fn on_proprietary(&mut self, _data: &ProprietaryData) {}
+
+    /// Called with the raw sentence (from `$`, without line ending) when its
+    /// type is not supported by the library
+    fn on_unknown(&mut self, _sentence: &[u8]) {}
+
+    /// Called when a complete sentence is invalid
+    fn on_error(&mut self, _error: ParseError) {}
+}
+
+/// Pass a decoded message to the matching handler method
+pub(crate) fn dispatch<H: MessageHandler + ?Sized>(handler: &mut H, msg: &NmeaMessage) {
+    match msg {
+        NmeaMessage::GGA(data) => handler.on_gga(data),
+        NmeaMessage::RMC(data) => handler.on_rmc(data),
+        NmeaMessage::GSA(data) => handler.on_gsa(data),
+        NmeaMessage::GSV(data) => handler.on_gsv(data),
+        NmeaMessage::GLL(data) => handler.on_gll(data),
+        NmeaMessage::VTG(data) => handler.on_vtg(data),
+        NmeaMessage::GNS(data) => handler.on_gns(data),
+        NmeaMessage::Query(data) => handler.on_query(data),
+        NmeaMessage::Proprietary(data) => handler.on_proprietary(data),
+        NmeaMessage::Unknown(data) => handler.on_unknown(data.as_bytes()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NmeaParser;
+
+    #[derive(Default)]
+    struct Counter {
+        gga: usize,
+        gll: usize,
+        gsv: usize,
+        unknown: usize,
+        errors: usize,
+        last_unknown_len: usize,
+    }
+
+    impl MessageHandler for Counter {
+        fn wants(&self, message_type: MessageType) -> bool {
+            message_type != MessageType::GSV
+        }
+
+        fn on_gga(&mut self, _data: &GgaData) {
+            self.gga += 1;
+        }
+
+        fn on_gll(&mut self, _data: &GllData) {
+            self.gll += 1;
+        }
+
+        fn on_gsv(&mut self, _data: &GsvData) {
+            self.gsv += 1;
+        }
+
+        fn on_unknown(&mut self, sentence: &[u8]) {
+            self.unknown += 1;
+            self.last_unknown_len = sentence.len();
+        }
+
+        fn on_error(&mut self, _error: ParseError) {
+            self.errors += 1;
+        }
+    }
+
+    #[test]
+    fn test_parse_into_dispatches_by_type() {
+        let parser = NmeaParser::new();
+        let data = b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n\
+                     $GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n\
+                     $GPGSV,2,1,08,01,40,083,46,02,17,308,41,12,07,344,39,14,22,228,45*75\r\n\
+                     $GPTXT,01,01,02,Software Version 7.03.00 (12345)*1C\r\n\
+                     $GPGLL,4916.45,N,12311.12,W,225444,A,*00\r\n\
+                     $GPGGA,1235";
+
+        let mut counter = Counter::default();
+        let consumed = parser.parse_into(data, &mut counter);
+
+        assert_eq!(counter.gga, 1);
+        assert_eq!(counter.gll, 1);
+        assert_eq!(counter.gsv, 0); // not wanted
+        assert_eq!(counter.unknown, 1);
+        assert_eq!(counter.last_unknown_len, 51);
+        assert_eq!(counter.errors, 1);
+        assert_eq!(&data[consumed..], b"$GPGGA,1235");
+    }
+
+    #[test]
+    fn test_parse_into_restrictive_filter() {
+        struct GgaOnly(Counter);
+
+        impl MessageHandler for GgaOnly {
+            fn wants(&self, message_type: MessageType) -> bool {
+                message_type == MessageType::GGA
+            }
+
+            fn on_gga(&mut self, data: &GgaData) {
+                self.0.on_gga(data);
+            }
+
+            fn on_gll(&mut self, data: &GllData) {
+                self.0.on_gll(data);
+            }
+
+            fn on_unknown(&mut self, sentence: &[u8]) {
+                self.0.on_unknown(sentence);
+            }
+        }
+
+        let parser = NmeaParser::new();
+        let data = b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n\
+                     $GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n\
+                     $GPTXT,01,01,02,Software Version 7.03.00 (12345)*1C\r\n";
+
+        let mut handler = GgaOnly(Counter::default());
+        assert_eq!(parser.parse_into(data, &mut handler), data.len());
+        assert_eq!(handler.0.gga, 1);
+        assert_eq!(handler.0.gll, 0);
+        assert_eq!(handler.0.unknown, 0);
+    }
+
+    #[test]
+    fn test_parse_into_default_methods() {
+        struct Nothing;
+        impl MessageHandler for Nothing {}
+
+        let parser = NmeaParser::new();
+        let data = b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+
+        assert_eq!(parser.parse_into(data, &mut Nothing), data.len());
+    }
+}
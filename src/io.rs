@@ -0,0 +1,189 @@
+//! Adapter for `std::io::Read` sources
+//!
+//! Enabled with the `std` feature. [`NmeaReader`] reads from any
+//! [`std::io::Read`] implementation (files, serial ports, sockets) and
+//! iterates over the messages it contains.
+
+use std::fmt;
+use std::io::{self, Read};
+
+use crate::decoder::{MessageDecoder, NoDecoder};
+use crate::parser::NmeaParser;
+use crate::stream::StreamParser;
+use crate::types::ParseError;
+
+/// Error yielded by `NmeaReader`
+#[derive(Debug)]
+pub enum ReadError {
+    /// The underlying reader failed
+    Io(io::Error),
+    /// A complete sentence was received but it's invalid
+    Parse(ParseError),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(err) => write!(f, "read error: {}", err),
+            ReadError::Parse(err) => write!(f, "parse error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReadError::Io(err) => Some(err),
+            ReadError::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(err: io::Error) -> Self {
+        ReadError::Io(err)
+    }
+}
+
+/// Reader yielding NMEA messages from a `std::io::Read` source, buffering up
+/// to `N` bytes of partial sentence
+///
+/// Iteration ends when the underlying reader reaches end of file.
+///
+/// # Example
+///
+/// ```
+/// use rustedbytes_nmea::io::NmeaReader;
+///
+/// let log: &[u8] = b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+///
+/// for result in NmeaReader::<_, 1024>::new(log) {
+///     match result {
+///         Ok(msg) => println!("{:?}", msg.message_type()),
+///         Err(err) => eprintln!("{}", err),
+///     }
+/// }
+/// ```
+pub struct NmeaReader<R, const N: usize, D = NoDecoder> {
+    reader: R,
+    stream: StreamParser<N, D>,
+}
+
+impl<R: Read, const N: usize> NmeaReader<R, N> {
+    /// Create a reader using a default `NmeaParser`
+    pub fn new(reader: R) -> Self {
+        Self::with_parser(reader, NmeaParser::new())
+    }
+}
+
+impl<R: Read, const N: usize, D: MessageDecoder> NmeaReader<R, N, D> {
+    /// Create a reader using a configured `NmeaParser`, yielding the messages
+    /// produced by its decoder
+    pub fn with_parser(reader: R, parser: NmeaParser<D>) -> Self {
+        NmeaReader {
+            reader,
+            stream: StreamParser::with_parser(parser),
+        }
+    }
+
+    /// Read the next complete message
+    ///
+    /// Returns `None` at end of file. Invalid sentences are reported as
+    /// `ReadError::Parse`; reading can continue after them.
+    pub fn next_message(&mut self) -> Option<Result<D::Message, ReadError>> {
+        loop {
+            if let Some(result) = self.stream.next_message() {
+                return Some(result.map_err(ReadError::Parse));
+            }
+
+            match self.reader.read(self.stream.spare_capacity()) {
+                Ok(0) => return None,
+                Ok(read) => self.stream.commit(read),
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Some(Err(ReadError::Io(err))),
+            }
+        }
+    }
+
+    /// Consume the adapter, returning the underlying reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read, const N: usize, D: MessageDecoder> Iterator for NmeaReader<R, N, D> {
+    type Item = Result<D::Message, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_message()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::MessageType;
+
+    #[test]
+    fn test_reader_iterates_messages() {
+        let data: &[u8] = b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n\
+                            $GPGLL,4916.45,N,12311.12,W,225444,A,*00\r\n\
+                            $GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+
+        let mut reader = NmeaReader::<_, 80>::new(data);
+
+        let msg = reader.next().unwrap().unwrap();
+        assert_eq!(msg.message_type(), MessageType::GGA);
+        assert!(matches!(
+            reader.next(),
+            Some(Err(ReadError::Parse(ParseError::InvalidChecksum(_))))
+        ));
+        assert!(reader.next().unwrap().is_ok());
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_reader_with_decoder() {
+        use crate::message::GenericSentence;
+        use crate::SentenceDecoder;
+
+        struct WaterTemperature;
+
+        impl SentenceDecoder for WaterTemperature {
+            type Output = f32;
+
+            fn accepts(&self, sentence: &GenericSentence) -> bool {
+                sentence.sentence_formatter() == "MTW"
+            }
+
+            fn decode(&self, sentence: &GenericSentence) -> Option<f32> {
+                sentence.field(0)?.parse().ok()
+            }
+        }
+
+        let data: &[u8] = b"$IIMTW,18.5,C*1F\r\n$IIMTW,,C*0D\r\n";
+        let parser = NmeaParser::builder().decoder(WaterTemperature).build();
+        let mut reader = NmeaReader::<_, 80, _>::with_parser(data, parser);
+
+        let decoded = reader.next().unwrap();
+        assert_eq!(decoded.unwrap().as_custom(), Some(&18.5));
+        assert!(matches!(
+            reader.next(),
+            Some(Err(ReadError::Parse(ParseError::InvalidMessage(_))))
+        ));
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_reader_io_error() {
+        struct Failing;
+        impl Read for Failing {
+            fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("disconnected"))
+            }
+        }
+
+        let mut reader = NmeaReader::<_, 80>::new(Failing);
+        assert!(matches!(reader.next(), Some(Err(ReadError::Io(_)))));
+    }
+}
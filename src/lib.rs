@@ -3,19 +3,95 @@
 //! NMEA 0183 parser library
 //!
 //! This library provides a `no_std` compatible NMEA 0183 parser for parsing
-//! GPS/GNSS data from receivers.
-
+//! GPS/GNSS data from receivers. Parsed messages can be written back as
+//! sentences with [`NmeaMessage::encode`], and the [`checksum`] module
+//! validates raw sentences without parsing them.
+//!
+//! Error types implement `Display` and `core::error::Error` without
+//! requiring `std`. The optional `std` feature adds an adapter over
+//! `std::io::Read` ([`io::NmeaReader`]) and owned `String` getters.
+//!
+//! The optional `fixed-point` feature adds accessors returning coordinates
+//! as integers in 1e-7 degrees (see [`Coordinate`]). The optional `serde`
+//! feature derives `Serialize`/`Deserialize` for messages and error types,
+//! and the `defmt` feature implements `defmt::Format` for them.
+
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+#[cfg(feature = "embedded-io-async")]
+pub mod asynch;
+pub mod checksum;
+mod clock;
+mod coordinate;
+mod datetime;
+mod decoder;
+mod encode;
+mod fixed_str;
+mod handler;
+#[cfg(feature = "std")]
+pub mod io;
 mod message;
+mod number;
 mod parser;
+mod router;
+mod satellites;
+mod scan;
+#[cfg(feature = "serde")]
+mod serde_impls;
+#[cfg(feature = "std")]
+mod std_impls;
+mod stream;
+mod tag_block;
 mod types;
+mod ubx;
 
 // Re-export public API
-pub use message::{Field, GgaData, GllData, GsaData, GsvData, RmcData, SatelliteInfo, VtgData};
-pub use parser::NmeaParser;
+pub use clock::{Clock, Timestamped};
+#[cfg(feature = "fixed-point")]
+pub use coordinate::COORDINATE_SCALE;
+pub use coordinate::{Coordinate, Degrees};
+pub use datetime::{NmeaDate, NmeaTime};
+pub use decoder::{Decoded, MessageDecoder, NoDecoder, SentenceDecoder};
+pub use encode::EncodeError;
+pub use handler::MessageHandler;
+pub use message::{
+    GenericSentence, GgaBuilder, GgaData, GllData, GnsData, GsaData, GsvData, ProprietaryData,
+    QueryData, RmcBuilder, RmcData, SatelliteInfo, SentenceRef, VtgData,
+};
+pub use number::Number;
+pub use parser::{
+    NmeaIter, NmeaParser, NmeaParserBuilder, DEFAULT_MAX_SENTENCE_LEN, MAX_ACCEPTED_TALKERS,
+};
+pub use router::{NmeaRouter, RouteFilter, SentenceSink};
+pub use satellites::{Satellite, SatelliteTable};
+pub use stream::StreamParser;
+pub use tag_block::{TagBlock, TagGroup};
 pub use types::*;
 
 /// Parse result type: returns optional message and bytes consumed, or error with bytes consumed
-pub type ParseResult = Result<(Option<NmeaMessage>, usize), (ParseError, usize)>;
+///
+/// `M` is `NmeaMessage`, or `Decoded<T>` for a parser with a custom `SentenceDecoder`.
+pub type ParseResult<M = NmeaMessage> = Result<(Option<M>, usize), (ParseError, usize)>;
+
+/// Tagged parse result type: like `ParseResult`, also carrying the TAG block preceding the message
+pub type TaggedParseResult<M = NmeaMessage> =
+    Result<(Option<M>, Option<TagBlock>, usize), (ParseError, usize)>;
+
+/// Timestamped parse result type: like `ParseResult`, with the message stamped with its reception time
+pub type TimestampedParseResult<M = NmeaMessage> =
+    Result<(Option<Timestamped<M>>, usize), (ParseError, usize)>;
+
+/// Raw parse result type: like `TaggedParseResult`, carrying the raw sentence bytes instead of the TAG block
+pub type RawParseResult<'a, M = NmeaMessage> =
+    Result<(Option<M>, Option<&'a [u8]>, usize), (ParseError, usize)>;
+
+/// Zero-copy parse result type: like `ParseResult`, with a `SentenceRef` borrowing the input
+pub type RefParseResult<'a, D = NoDecoder> =
+    Result<(Option<SentenceRef<'a, D>>, usize), (ParseError, usize)>;
+
+/// Hinted parse result type: like `ParseResult`, with a `BufferHint` instead of the bytes consumed
+pub type HintedParseResult<M = NmeaMessage> = Result<(Option<M>, BufferHint), (ParseError, usize)>;
 
 #[cfg(test)]
 mod tests {
@@ -67,6 +143,11 @@ mod tests {
                 b"$GPVTG,054.7,T,034.4,M,005.5,N,010.2,K*48\r\n".as_slice(),
                 MessageType::VTG,
             ),
+            (
+                b"$GNGNS,122310.0,3723.46587,N,12202.26957,W,AAAN,12,0.9,1005.543,6.5,,,V*02\r\n"
+                    .as_slice(),
+                MessageType::GNS,
+            ),
         ];
 
         for (sentence, expected_type) in test_cases {
@@ -114,7 +195,15 @@ mod tests {
 
         assert_eq!(msg.message_type(), MessageType::GGA);
         let gga = msg.as_gga().expect("Expected GGA message");
-        assert_eq!(gga.time(), "123519");
+        assert_eq!(
+            gga.time(),
+            NmeaTime {
+                hour: 12,
+                minute: 35,
+                second: 19,
+                millis: 0
+            }
+        );
         assert_eq!(gga.latitude, 4807.038);
     }
 
@@ -152,12 +241,20 @@ mod tests {
         assert!(gga.is_some());
 
         let gga_data = gga.unwrap();
-        assert_eq!(gga_data.time(), "123519");
+        assert_eq!(
+            gga_data.time(),
+            NmeaTime {
+                hour: 12,
+                minute: 35,
+                second: 19,
+                millis: 0
+            }
+        );
         assert_eq!(gga_data.latitude, 4807.038);
         assert_eq!(gga_data.lat_direction, 'N');
         assert_eq!(gga_data.longitude, 1131.000);
         assert_eq!(gga_data.lon_direction, 'E');
-        assert_eq!(gga_data.fix_quality, 1);
+        assert_eq!(gga_data.fix_quality, FixQuality::Gps);
         assert_eq!(gga_data.num_satellites, Some(8));
         assert_eq!(gga_data.hdop, Some(0.9));
         assert_eq!(gga_data.altitude, Some(545.4));
@@ -177,15 +274,30 @@ mod tests {
         assert!(rmc.is_some());
 
         let rmc_data = rmc.unwrap();
-        assert_eq!(rmc_data.time(), "123519");
-        assert_eq!(rmc_data.status, 'A');
+        assert_eq!(
+            rmc_data.time(),
+            NmeaTime {
+                hour: 12,
+                minute: 35,
+                second: 19,
+                millis: 0
+            }
+        );
+        assert_eq!(rmc_data.status, Status::Valid);
         assert_eq!(rmc_data.latitude, 4807.038);
         assert_eq!(rmc_data.lat_direction, 'N');
         assert_eq!(rmc_data.longitude, 1131.000);
         assert_eq!(rmc_data.lon_direction, 'E');
         assert_eq!(rmc_data.speed_knots, 22.4);
         assert_eq!(rmc_data.track_angle, 84.4);
-        assert_eq!(rmc_data.date(), "230394");
+        assert_eq!(
+            rmc_data.date(),
+            NmeaDate {
+                year: 1994,
+                month: 3,
+                day: 23
+            }
+        );
     }
 
     #[test]
@@ -202,7 +314,7 @@ mod tests {
 
         let gsa_data = gsa.unwrap();
         assert_eq!(gsa_data.mode, 'A');
-        assert_eq!(gsa_data.fix_type, 3);
+        assert_eq!(gsa_data.fix_type, FixType::Fix3D);
         assert_eq!(gsa_data.satellite_ids[0], Some(4));
         assert_eq!(gsa_data.satellite_ids[1], Some(5));
         assert_eq!(gsa_data.satellite_ids[3], Some(9));
@@ -254,8 +366,16 @@ mod tests {
         assert_eq!(gll_data.lat_direction, 'N');
         assert_eq!(gll_data.longitude, 12311.12);
         assert_eq!(gll_data.lon_direction, 'W');
-        assert_eq!(gll_data.time(), "225444");
-        assert_eq!(gll_data.status, 'A');
+        assert_eq!(
+            gll_data.time(),
+            NmeaTime {
+                hour: 22,
+                minute: 54,
+                second: 44,
+                millis: 0
+            }
+        );
+        assert_eq!(gll_data.status, Status::Valid);
     }
 
     #[test]
@@ -304,20 +424,20 @@ mod tests {
     fn test_gga_with_empty_fields() {
         let parser = NmeaParser::new();
         // GGA message with some empty mandatory fields should fail to parse
-        let sentence = b"$GPGGA,123519,,N,,E,1,,,,,M,,M,,*47\r\n";
+        let sentence = b"$GPGGA,123519,,N,,E,1,,,,,M,,M,,*4D\r\n";
 
         let result = parser.parse_bytes(sentence);
         // Should return error because mandatory fields (latitude, longitude) are empty
         assert!(result.is_err());
         let (err, _consumed) = result.unwrap_err();
-        assert_eq!(err, ParseError::InvalidMessage);
+        assert!(matches!(err, ParseError::InvalidMessage(_)));
     }
 
     #[test]
     fn test_rmc_with_empty_status() {
         let parser = NmeaParser::new();
         // RMC message with void status (still valid)
-        let sentence = b"$GPRMC,123519,V,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A\r\n";
+        let sentence = b"$GPRMC,123519,V,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*7D\r\n";
 
         let result = parser.parse_bytes(sentence);
         assert!(result.is_ok());
@@ -327,14 +447,14 @@ mod tests {
         assert!(rmc.is_some());
 
         let rmc_data = rmc.unwrap();
-        assert_eq!(rmc_data.status, 'V');
+        assert_eq!(rmc_data.status, Status::Invalid);
     }
 
     #[test]
     fn test_gsa_with_partial_satellites() {
         let parser = NmeaParser::new();
         // GSA message with only a few satellites
-        let sentence = b"$GPGSA,A,3,01,,,,,,,,,,,,2.5,1.3,2.1*39\r\n";
+        let sentence = b"$GPGSA,A,3,01,,,,,,,,,,,,2.5,1.3,2.1*35\r\n";
 
         let result = parser.parse_bytes(sentence);
         assert!(result.is_ok());
@@ -345,7 +465,7 @@ mod tests {
 
         let gsa_data = gsa.unwrap();
         assert_eq!(gsa_data.mode, 'A');
-        assert_eq!(gsa_data.fix_type, 3);
+        assert_eq!(gsa_data.fix_type, FixType::Fix3D);
         assert_eq!(gsa_data.satellite_ids[0], Some(1));
         assert_eq!(gsa_data.satellite_ids[1], None);
         assert_eq!(gsa_data.pdop, Some(2.5));
@@ -357,7 +477,7 @@ mod tests {
     fn test_gsv_with_partial_satellite_data() {
         let parser = NmeaParser::new();
         // GSV message with only two satellites
-        let sentence = b"$GPGSV,1,1,02,01,40,083,46,02,17,308,*75\r\n";
+        let sentence = b"$GPGSV,1,1,02,01,40,083,46,02,17,308,*78\r\n";
 
         let result = parser.parse_bytes(sentence);
         assert!(result.is_ok());
@@ -422,18 +542,18 @@ mod tests {
     fn test_messages_with_missing_mandatory_fields() {
         let parser = NmeaParser::new();
         let test_cases = [
-            b"$GPGGA,,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n".as_slice(), // Missing time
-            b"$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,,003.1,W*6A\r\n".as_slice(), // Missing date
-            b"$GPGSA,,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39\r\n".as_slice(), // Missing mode
-            b"$GPGSV,,1,08,01,40,083,46,02,17,308,41,12,07,344,39,14,22,228,45*75\r\n".as_slice(), // Missing num_messages
-            b"$GPGLL,4916.45,N,12311.12,W,225444,,*1D\r\n".as_slice(), // Missing status
+            b"$GPGGA,,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*4A\r\n".as_slice(), // Missing time
+            b"$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,,003.1,W*65\r\n".as_slice(), // Missing date
+            b"$GPGSA,,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*78\r\n".as_slice(), // Missing mode
+            b"$GPGSV,,1,08,01,40,083,46,02,17,308,41,12,07,344,39,14,22,228,45*47\r\n".as_slice(), // Missing num_messages
+            b"$GPGLL,4916.45,N,12311.12,W,225444,,*5C\r\n".as_slice(), // Missing status
         ];
 
         for sentence in test_cases {
             let result = parser.parse_bytes(sentence);
             assert!(result.is_err());
             let (err, _consumed) = result.unwrap_err();
-            assert_eq!(err, ParseError::InvalidMessage);
+            assert!(matches!(err, ParseError::InvalidMessage(_)));
         }
     }
 
@@ -520,12 +640,12 @@ mod tests {
     fn test_invalid_message_returns_error() {
         let parser = NmeaParser::new();
         // Complete message but with missing mandatory field
-        let invalid = b"$GPGGA,,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n";
+        let invalid = b"$GPGGA,,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*4A\r\n";
 
         let result = parser.parse_bytes(invalid);
         assert!(result.is_err());
         let (err, _consumed) = result.unwrap_err();
-        assert_eq!(err, ParseError::InvalidMessage);
+        assert!(matches!(err, ParseError::InvalidMessage(_)));
     }
 
     #[test]
@@ -550,4 +670,247 @@ mod tests {
         let msg2 = msg2.unwrap();
         assert_eq!(msg2.message_type(), MessageType::RMC);
     }
+
+    #[cfg(feature = "defmt")]
+    #[test]
+    fn test_defmt_format_implemented() {
+        fn assert_format<T: defmt::Format>() {}
+
+        assert_format::<NmeaMessage>();
+        assert_format::<ParseError>();
+        assert_format::<TagBlock>();
+        assert_format::<Satellite>();
+        assert_format::<Decoded<u8>>();
+    }
+
+    #[test]
+    fn test_decimal_degrees_consistent_across_messages() {
+        let parser = NmeaParser::new();
+        let data = b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n\
+                     $GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A\r\n\
+                     $GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+
+        let mut iter = parser.iter(data);
+        let gga = iter.next().unwrap().0.unwrap();
+        let rmc = iter.next().unwrap().0.unwrap();
+        let gll = iter.next().unwrap().0.unwrap();
+
+        let gga = gga.as_gga().unwrap();
+        let rmc = rmc.as_rmc().unwrap();
+        assert_eq!(gga.latitude_degrees(), rmc.latitude_degrees());
+        assert_eq!(gga.longitude_degrees(), rmc.longitude_degrees());
+        assert!(gga.latitude_degrees().unwrap() > Degrees::default());
+
+        let gll = gll.as_gll().unwrap();
+        assert!(gll.latitude_degrees().unwrap() > Degrees::default());
+        assert!(gll.longitude_degrees().unwrap() < Degrees::default());
+    }
+
+    #[test]
+    fn test_encode_round_trip() {
+        let parser = NmeaParser::new();
+        let sentences: [&[u8]; 12] = [
+            b"$GPGGA,123519,4807.03800,N,01131.00000,E,1,08,0.9,545.4,M,46.9,M,,0120*44\r\n",
+            b"$GPRMC,225446.50,A,4916.45000,N,12311.12000,W,0.5,54.7,191194,20.3,E,A*2E\r\n",
+            b"$GNGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1,1*3A\r\n",
+            b"$GPGSV,2,1,08,01,40,083,46,02,17,308,41,12,07,344,39,14,22,228,45*75\r\n",
+            b"$GPGSV,3,3,09,25,,,38,1*61\r\n",
+            b"$GPGLL,4916.45000,N,12311.12000,W,225444,A*31\r\n",
+            b"$GPVTG,54.7,T,34.4,M,5.5,N,10.2,K*78\r\n",
+            b"$GPGNS,122310,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,,S*08\r\n",
+            b"$CCGPQ,GGA*2B\r\n",
+            b"$PGRME,15.0,M,45.0,M,25.0,M*1C\r\n",
+            b"$GPTXT,01,01,02,ANTSTATUS=OK*3B\r\n",
+            b"$GPRMC,123519,A,4807.03800,N,01131.00000,E,22.4,84.4,230394,3.1,W*6A\r\n",
+        ];
+
+        let mut buffer = [0u8; 82];
+        for sentence in sentences {
+            let msg = parser.parse_sentence_complete(sentence).unwrap();
+            let len = msg.encode(&mut buffer).unwrap();
+            assert_eq!(
+                core::str::from_utf8(&buffer[..len]),
+                core::str::from_utf8(sentence)
+            );
+        }
+    }
+
+    #[test]
+    fn test_encode_normalizes_fields() {
+        let parser = NmeaParser::new();
+        let msg = parser
+            .parse_sentence_complete(b"$GPGLL,4916.45,N,12311.12,W,225444.5,A,*06\r\n")
+            .unwrap();
+
+        let mut buffer = [0u8; 82];
+        let len = msg.encode(&mut buffer).unwrap();
+        let encoded = &buffer[..len];
+        assert!(encoded.starts_with(b"$GPGLL,4916.45000,N,12311.12000,W,225444.50,A*"));
+
+        let reparsed = parser.parse_sentence_complete(encoded).unwrap();
+        let (gll, again) = (msg.as_gll().unwrap(), reparsed.as_gll().unwrap());
+        assert_eq!(gll.latitude, again.latitude);
+        assert_eq!(gll.longitude, again.longitude);
+        assert_eq!(gll.time(), again.time());
+
+        assert_eq!(
+            msg.encode(&mut buffer[..len - 1]),
+            Err(EncodeError::BufferTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_encode_uses_typed_fields() {
+        let parser = NmeaParser::new();
+        let mut buffer = [0u8; 82];
+        let mut encode = |msg: NmeaMessage| {
+            let len = msg.encode(&mut buffer).unwrap();
+            parser.parse_sentence_complete(&buffer[..len]).unwrap()
+        };
+
+        let msg = parser
+            .parse_sentence_complete(
+                b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n",
+            )
+            .unwrap();
+        let NmeaMessage::GGA(mut gga) = msg else {
+            panic!("expected GGA");
+        };
+        gga.fix_quality = FixQuality::from_u8(4);
+        let reparsed = encode(NmeaMessage::GGA(gga.clone()));
+        assert_eq!(reparsed.as_gga().unwrap().fix_quality_raw(), 4);
+        // Unmapped values are kept as received
+        gga.fix_quality = FixQuality::Unknown;
+        let reparsed = encode(NmeaMessage::GGA(gga));
+        assert_eq!(reparsed.as_gga().unwrap().fix_quality_raw(), 1);
+
+        let msg = parser
+            .parse_sentence_complete(
+                b"$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A\r\n",
+            )
+            .unwrap();
+        let NmeaMessage::RMC(mut rmc) = msg else {
+            panic!("expected RMC");
+        };
+        rmc.status = Status::Invalid;
+        let reparsed = encode(NmeaMessage::RMC(rmc));
+        assert_eq!(reparsed.as_rmc().unwrap().status_raw(), 'V');
+
+        let msg = parser
+            .parse_sentence_complete(b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n")
+            .unwrap();
+        let NmeaMessage::GLL(mut gll) = msg else {
+            panic!("expected GLL");
+        };
+        gll.status = Status::Invalid;
+        let reparsed = encode(NmeaMessage::GLL(gll));
+        assert_eq!(reparsed.as_gll().unwrap().status_raw(), 'V');
+
+        let msg = parser
+            .parse_sentence_complete(b"$GPGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39\r\n")
+            .unwrap();
+        let NmeaMessage::GSA(mut gsa) = msg else {
+            panic!("expected GSA");
+        };
+        gsa.fix_type = FixType::Fix2D;
+        let reparsed = encode(NmeaMessage::GSA(gsa));
+        assert_eq!(reparsed.as_gsa().unwrap().fix_type_raw(), 2);
+    }
+
+    #[cfg(feature = "fixed-point")]
+    #[test]
+    fn test_fixed_point_coordinates() {
+        let parser = NmeaParser::new();
+
+        let (msg, _) = parser
+            .parse_bytes(b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n")
+            .unwrap();
+        let msg = msg.unwrap();
+        let gga = msg.as_gga().unwrap();
+        assert_eq!(gga.latitude_fixed(), Some(481_173_000));
+        assert_eq!(gga.longitude_fixed(), Some(115_166_667));
+
+        let (msg, _) = parser
+            .parse_bytes(
+                b"$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A\r\n",
+            )
+            .unwrap();
+        let msg = msg.unwrap();
+        let rmc = msg.as_rmc().unwrap();
+        assert_eq!(rmc.latitude_fixed(), Some(481_173_000));
+        assert_eq!(rmc.longitude_fixed(), Some(115_166_667));
+
+        let (msg, _) = parser
+            .parse_bytes(b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n")
+            .unwrap();
+        let msg = msg.unwrap();
+        let gll = msg.as_gll().unwrap();
+        assert_eq!(gll.latitude_fixed(), Some(492_741_667));
+        assert_eq!(gll.longitude_fixed(), Some(-1_231_853_333));
+
+        let (msg, _) = parser
+            .parse_bytes(
+                b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*69\r\n",
+            )
+            .unwrap();
+        let msg = msg.unwrap();
+        let gns = msg.as_gns().unwrap();
+        assert_eq!(gns.latitude_fixed(), Some(373_910_978));
+        assert_eq!(gns.longitude_fixed(), Some(-1_220_378_262));
+        assert_eq!(gns.latitude_fixed().unwrap() / COORDINATE_SCALE, 37);
+        // The raw fields are the same as without the feature
+        assert_eq!(gns.latitude, 3723.46587);
+    }
+
+    #[test]
+    fn test_coordinate_validation_same_in_all_modes() {
+        // Runs with and without `fixed-point`: the same fields are accepted
+        // and rejected, and both representations agree on accepted ones
+        let parser = NmeaParser::new();
+        let cases = [
+            ("4807.038", true),
+            ("0000.000", true),
+            ("9000.0000", true),
+            ("4807", true),
+            ("4860.000", false),
+            ("-4807.038", false),
+            ("18100.000", false),
+            ("48a7.038", false),
+            (".5", false),
+            ("1e3", false),
+        ];
+
+        for (latitude, valid) in cases {
+            let mut buffer = [0u8; 82];
+            let len = encode::SentenceWriter::new(&mut buffer, TalkerId::GP, "GLL")
+                .and_then(|mut writer| {
+                    writer.field(Some(latitude))?;
+                    writer.field(Some("N"))?;
+                    writer.field(Some("01131.000"))?;
+                    writer.field(Some("E"))?;
+                    writer.field(Some("225444"))?;
+                    writer.field(Some("A"))?;
+                    writer.finish()
+                })
+                .unwrap();
+
+            let msg = parser.parse_sentence_complete(&buffer[..len]);
+            let gll = msg.as_ref().and_then(NmeaMessage::as_gll);
+            assert_eq!(gll.is_some(), valid, "{latitude}");
+
+            if let Some(gll) = gll {
+                let degrees = gll.latitude_degrees().unwrap();
+                #[cfg(feature = "fixed-point")]
+                {
+                    let fixed = gll.latitude_fixed().unwrap();
+                    let scale = f64::from(COORDINATE_SCALE);
+                    assert!(
+                        (f64::from(fixed) / scale - degrees).abs() < 1e-7,
+                        "{latitude}"
+                    );
+                }
+                assert!((0.0..=90.0).contains(&degrees));
+            }
+        }
+    }
 }
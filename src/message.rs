@@ -4,57 +4,206 @@
 //! and fields. Message-specific parsing implementations are included in separate
 //! submodules for each message type.
 
+use crate::coordinate::{self, Coordinate};
+use crate::datetime::{NmeaDate, NmeaTime};
+use crate::number::{self, Number};
+use crate::scan;
 use crate::types::*;
 
 // Message type implementations
+mod generic;
 mod gga;
 mod gll;
 mod gns;
 mod gsa;
 mod gsv;
+mod proprietary;
+mod query;
 mod rmc;
+mod sentence_ref;
 mod vtg;
 
 // Re-export message data structures
-pub use gga::GgaData;
+pub use generic::GenericSentence;
+pub(crate) use generic::MAX_GENERIC_LEN;
+pub use gga::{GgaBuilder, GgaData};
 pub use gll::GllData;
 pub use gns::GnsData;
 pub use gsa::GsaData;
 pub use gsv::{GsvData, SatelliteInfo};
-pub use rmc::RmcData;
+pub use proprietary::ProprietaryData;
+pub use query::QueryData;
+pub use rmc::{RmcBuilder, RmcData};
+pub use sentence_ref::SentenceRef;
 pub use vtg::VtgData;
 
 /// Maximum number of fields in an NMEA sentence
-pub(crate) const MAX_FIELDS: usize = 20;
+pub(crate) const MAX_FIELDS: usize = 24;
+
+/// Capacity of stored differential reference station IDs (`0000`-`1023`)
+pub(crate) const MAX_STATION_ID_LEN: usize = 4;
+/// Capacity of stored GNS mode indicators (one character per constellation)
+pub(crate) const MAX_MODE_LEN: usize = 6;
+
+/// Iterate over the comma-separated fields of sentence data, starting with the address
+///
+/// `data` is the part of the sentence between `$` and the checksum marker;
+/// fields that are not valid UTF-8 are yielded as empty strings.
+pub(crate) fn split_fields(data: &[u8]) -> impl Iterator<Item = &str> {
+    data.split(|&b| b == b',')
+        .map(|field| core::str::from_utf8(field).unwrap_or(""))
+}
 
 /// Parsed NMEA sentence data (internal representation)
 ///
 /// Represents a single parsed NMEA sentence with its type, fields, and metadata.
-/// This is an internal structure used during parsing.
+/// This is an internal structure used during parsing. Field boundaries are
+/// located when the sentence is scanned, but field contents are only converted
+/// when an accessor is called.
 #[derive(Debug, Clone)]
-pub(crate) struct ParsedSentence {
+pub(crate) struct ParsedSentence<'a> {
     pub message_type: MessageType,
     pub talker_id: TalkerId,
-    pub fields: [Option<Field>; MAX_FIELDS],
     pub field_count: usize,
+    /// Sentence data between `$` and the `*` checksum marker
+    data: &'a [u8],
+    /// Start and end offset of each field within `data`
+    bounds: [(u16, u16); MAX_FIELDS],
 }
 
-impl ParsedSentence {
+impl<'a> ParsedSentence<'a> {
+    /// Locate the comma-separated fields of the sentence data
+    ///
+    /// `data` is the part of the sentence between `$` and the checksum marker;
+    /// fields beyond `MAX_FIELDS` are ignored.
+    pub(crate) fn new(message_type: MessageType, talker_id: TalkerId, data: &'a [u8]) -> Self {
+        // Offsets are stored as u16, far above the 82-character sentence limit
+        let data = &data[..data.len().min(u16::MAX as usize)];
+        let mut bounds = [(0, 0); MAX_FIELDS];
+        let mut field_count = 0;
+        let mut start = 0;
+
+        while let Some(offset) = scan::find(&data[start..], b',') {
+            let end = start + offset;
+            bounds[field_count] = (start as u16, end as u16);
+            field_count += 1;
+            start = end + 1;
+            if field_count == MAX_FIELDS {
+                break;
+            }
+        }
+        if field_count < MAX_FIELDS {
+            bounds[field_count] = (start as u16, data.len() as u16);
+            field_count += 1;
+        }
+
+        ParsedSentence {
+            message_type,
+            talker_id,
+            field_count,
+            data,
+            bounds,
+        }
+    }
+
+    /// Helper to get the raw bytes of a field (`None` if missing or empty)
+    fn raw_field(&self, index: usize) -> Option<&'a [u8]> {
+        if index >= self.field_count {
+            return None;
+        }
+
+        let (start, end) = self.bounds[index];
+        let field = &self.data[start as usize..end as usize];
+        if field.is_empty() {
+            None
+        } else {
+            Some(field)
+        }
+    }
+
     /// Helper to get a field as a string slice
-    pub(crate) fn get_field_str(&self, index: usize) -> Option<&str> {
-        if index < self.field_count {
-            self.fields[index].as_ref()?.as_str()
+    ///
+    /// Returns `None` if the field is empty, missing or not valid UTF-8.
+    pub(crate) fn get_field_str(&self, index: usize) -> Option<&'a str> {
+        core::str::from_utf8(self.raw_field(index)?).ok()
+    }
+
+    /// Build the error detail for field `index` failing with `reason`
+    ///
+    /// The offset is counted from the `$`; a missing field is located at the
+    /// end of the sentence data.
+    pub(crate) fn field_error(&self, reason: ErrorReason, index: usize) -> ErrorDetail {
+        let offset = if index < self.field_count {
+            self.bounds[index].0 as usize + 1
         } else {
-            None
+            self.data.len() + 1
+        };
+        ErrorDetail::in_field(reason, index, offset)
+    }
+
+    /// Check that the sentence has the message type an extractor expects
+    pub(crate) fn expect_type(&self, message_type: MessageType) -> Result<(), ErrorDetail> {
+        if self.message_type == message_type {
+            Ok(())
+        } else {
+            Err(self.field_error(ErrorReason::UnknownType, 0))
         }
     }
 
-    /// Generic helper to parse a field using FromStr trait
+    /// Helper to get a mandatory field as a string slice
+    pub(crate) fn required_str(&self, index: usize) -> Result<&'a str, ErrorDetail> {
+        let field = self
+            .raw_field(index)
+            .ok_or_else(|| self.field_error(ErrorReason::MissingField, index))?;
+        core::str::from_utf8(field).map_err(|_| self.field_error(ErrorReason::InvalidValue, index))
+    }
+
+    /// Helper to parse a mandatory numeric field
+    pub(crate) fn required_field<T>(&self, index: usize) -> Result<T, ErrorDetail>
+    where
+        T: Number,
+    {
+        number::parse(self.required_str(index)?)
+            .ok_or_else(|| self.field_error(ErrorReason::InvalidNumber, index))
+    }
+
+    /// Helper to parse a mandatory latitude/longitude field
+    pub(crate) fn required_coordinate(&self, index: usize) -> Result<Coordinate, ErrorDetail> {
+        coordinate::parse(self.required_str(index)?)
+            .ok_or_else(|| self.field_error(ErrorReason::InvalidNumber, index))
+    }
+
+    /// Helper to parse a mandatory `hhmmss[.sss]` UTC time field
+    pub(crate) fn required_time(&self, index: usize) -> Result<NmeaTime, ErrorDetail> {
+        NmeaTime::parse(self.required_str(index)?)
+            .ok_or_else(|| self.field_error(ErrorReason::InvalidValue, index))
+    }
+
+    /// Helper to parse a mandatory `ddmmyy` date field
+    pub(crate) fn required_date(&self, index: usize) -> Result<NmeaDate, ErrorDetail> {
+        NmeaDate::parse(self.required_str(index)?)
+            .ok_or_else(|| self.field_error(ErrorReason::InvalidValue, index))
+    }
+
+    /// Helper to parse a mandatory field as char (first character)
+    pub(crate) fn required_char(&self, index: usize) -> Result<char, ErrorDetail> {
+        self.required_str(index)?
+            .chars()
+            .next()
+            .ok_or_else(|| self.field_error(ErrorReason::MissingField, index))
+    }
+
+    /// Generic helper to parse an optional numeric field
     pub(crate) fn parse_field<T>(&self, index: usize) -> Option<T>
     where
-        T: core::str::FromStr,
+        T: Number,
     {
-        self.get_field_str(index)?.parse().ok()
+        number::parse(self.get_field_str(index)?)
+    }
+
+    /// Helper to parse a field as a hexadecimal number (e.g. NMEA 4.11 system/signal IDs)
+    pub(crate) fn parse_field_hex(&self, index: usize) -> Option<u8> {
+        u8::from_str_radix(self.get_field_str(index)?, 16).ok()
     }
 
     /// Helper to parse a field as char (first character)
@@ -63,41 +212,82 @@ impl ParsedSentence {
     }
 }
 
-/// Represents a field value in an NMEA message
-///
-/// A field is a single data element within an NMEA sentence, stored as a
-/// fixed-size byte array with length tracking. This provides `no_std` compatible
-/// string storage without heap allocation.
-#[derive(Debug, Clone, Copy)]
-pub struct Field {
-    data: [u8; 16], // Reduced from 32 to 16 bytes - sufficient for most NMEA fields
-    len: u8,        // Changed from usize to u8 for memory efficiency
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::mem::size_of;
 
-impl Field {
-    /// Create a field from a byte slice
-    ///
-    /// Copies up to 16 bytes from the input slice.
-    pub(crate) fn from_bytes(bytes: &[u8]) -> Self {
-        let copy_len = bytes.len().min(16);
-        let mut data = [0; 16];
-        data[..copy_len].copy_from_slice(&bytes[..copy_len]);
+    #[test]
+    fn test_message_sizes() {
+        // Size regression guard: NmeaMessage is moved by value, keep it small
+        assert!(size_of::<GgaData>() <= 88);
+        assert!(size_of::<RmcData>() <= 80);
+        assert!(size_of::<GsaData>() <= 64);
+        assert!(size_of::<GsvData>() <= 56);
+        assert!(size_of::<GllData>() <= 56);
+        assert!(size_of::<VtgData>() <= 56);
+        assert!(size_of::<GnsData>() <= 88);
+        assert!(size_of::<GenericSentence>() <= 88);
+        assert!(size_of::<ProprietaryData>() <= 88);
+        assert!(size_of::<NmeaMessage>() <= 96);
+    }
 
-        Field {
-            data,
-            len: copy_len as u8,
-        }
+    #[test]
+    fn test_parsed_sentence_field_bounds() {
+        let parsed = ParsedSentence::new(MessageType::GLL, TalkerId::GP, b"GPGLL,4916.45,,W");
+
+        assert_eq!(parsed.field_count, 4);
+        assert_eq!(parsed.get_field_str(0), Some("GPGLL"));
+        assert_eq!(parsed.get_field_str(1), Some("4916.45"));
+        assert_eq!(parsed.get_field_str(2), None);
+        assert_eq!(parsed.get_field_str(3), Some("W"));
+        assert_eq!(parsed.get_field_str(4), None);
     }
 
-    /// Get the field as a string slice
-    ///
-    /// Returns `None` if the field contains invalid UTF-8.
-    pub fn as_str(&self) -> Option<&str> {
-        core::str::from_utf8(&self.data[..self.len as usize]).ok()
+    #[test]
+    fn test_parsed_sentence_required_field_errors() {
+        let parsed = ParsedSentence::new(MessageType::GLL, TalkerId::GP, b"GPGLL,49x6.45,,W");
+
+        assert_eq!(
+            parsed.required_field::<f64>(3).unwrap_err().reason,
+            ErrorReason::InvalidNumber
+        );
+        assert_eq!(
+            parsed.required_field::<f64>(1),
+            Err(ErrorDetail::in_field(ErrorReason::InvalidNumber, 1, 7))
+        );
+        assert_eq!(
+            parsed.required_char(2),
+            Err(ErrorDetail::in_field(ErrorReason::MissingField, 2, 15))
+        );
+        assert_eq!(
+            parsed.required_str(6),
+            Err(ErrorDetail::in_field(ErrorReason::MissingField, 6, 17))
+        );
+        assert_eq!(parsed.required_char(3), Ok('W'));
+        assert_eq!(
+            parsed.expect_type(MessageType::GGA).unwrap_err().reason,
+            ErrorReason::UnknownType
+        );
     }
 
-    /// Get the field as a byte slice
-    pub fn as_bytes(&self) -> &[u8] {
-        &self.data[..self.len as usize]
+    #[test]
+    fn test_parsed_sentence_long_field_not_truncated() {
+        let parsed = ParsedSentence::new(
+            MessageType::Unknown,
+            TalkerId::GP,
+            b"GPTXT,ANTENNA_STATUS_OPEN_CIRCUIT",
+        );
+
+        assert_eq!(parsed.get_field_str(1), Some("ANTENNA_STATUS_OPEN_CIRCUIT"));
+    }
+
+    #[test]
+    fn test_parsed_sentence_field_limit() {
+        let data = [b','; 40];
+        let parsed = ParsedSentence::new(MessageType::Unknown, TalkerId::GP, &data);
+
+        assert_eq!(parsed.field_count, MAX_FIELDS);
+        assert_eq!(parsed.get_field_str(MAX_FIELDS - 1), None);
     }
 }
@@ -0,0 +1,171 @@
+//! Generic sentence implementation
+//!
+//! Sentences whose formatter is not supported by the library are not
+//! rejected: they are kept as a [`GenericSentence`] holding the raw sentence,
+//! so applications can decode receiver-specific sentences themselves.
+//!
+//! ## Example
+//!
+//! ```text
+//! $GPTXT,01,01,02,ANTSTATUS=OK*3B
+//! ```
+//!
+//! This is exposed as:
+//! - Address: GPTXT (talker GP, formatter TXT)
+//! - Fields: `01`, `01`, `02`, `ANTSTATUS=OK`
+
+use crate::encode::{EncodeError, SentenceWriter};
+use crate::fixed_str::FixedStr;
+use crate::message::split_fields;
+use crate::scan;
+use crate::types::TalkerId;
+
+/// Maximum length of a sentence kept by `GenericSentence` (from `$` to the
+/// checksum, without line ending): the 82-character NMEA limit minus `<CR><LF>`
+pub(crate) const MAX_GENERIC_LEN: usize = 80;
+
+/// Sentence of a type not decoded by the library, with its raw fields
+///
+/// The sentence is copied into a fixed buffer of 80 bytes (from `$` to the
+/// checksum), the NMEA limit without line ending, independently of
+/// `NmeaParserBuilder::max_sentence_length`. Longer sentences are reported as
+/// `ParseError::InvalidMessage` with `ErrorReason::TooLong`; use
+/// `NmeaParser::parse_bytes_ref` to access them without copying.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct GenericSentence {
+    pub talker_id: TalkerId,
+    raw: FixedStr<MAX_GENERIC_LEN>,
+    /// End of the data part (before the `*` checksum marker) within `raw`
+    data_end: u8,
+}
+
+impl GenericSentence {
+    /// Create a generic sentence from a complete sentence starting with `$`
+    ///
+    /// Returns `None` if the sentence is longer than `MAX_GENERIC_LEN`.
+    pub(crate) fn from_sentence(sentence: &[u8], talker_id: TalkerId) -> Option<Self> {
+        if sentence.len() > MAX_GENERIC_LEN {
+            return None;
+        }
+
+        let data_end = scan::find(sentence, b'*').unwrap_or(sentence.len());
+
+        Some(GenericSentence {
+            talker_id,
+            raw: FixedStr::new(sentence),
+            data_end: data_end as u8,
+        })
+    }
+
+    /// Get the raw sentence bytes (from `$` to the checksum, without line ending)
+    pub fn as_bytes(&self) -> &[u8] {
+        self.raw.as_bytes()
+    }
+
+    /// Get the address field as string slice (e.g. "GPTXT")
+    pub fn address(&self) -> &str {
+        self.raw_fields().next().unwrap_or("")
+    }
+
+    /// Get the sentence formatter as string slice (e.g. "TXT")
+    pub fn sentence_formatter(&self) -> &str {
+        self.address().get(2..).unwrap_or("")
+    }
+
+    /// Number of data fields following the address field
+    pub fn field_count(&self) -> usize {
+        self.raw_fields().count() - 1
+    }
+
+    /// Get a data field as string slice (index 0 is the first field after the address)
+    ///
+    /// Returns `None` if the field is empty, missing or not valid UTF-8.
+    pub fn field(&self, index: usize) -> Option<&str> {
+        self.raw_fields()
+            .nth(index + 1)
+            .filter(|field| !field.is_empty())
+    }
+
+    /// Iterate over the data fields following the address field
+    ///
+    /// Empty fields are yielded as empty strings.
+    pub fn fields(&self) -> impl Iterator<Item = &str> {
+        self.raw_fields().skip(1)
+    }
+
+    /// Encode the sentence into `buffer` with a recomputed checksum,
+    /// returning the sentence length
+    pub fn encode(&self, buffer: &mut [u8]) -> Result<usize, EncodeError> {
+        let data = &self.raw.as_bytes()[1..self.data_end as usize];
+        SentenceWriter::raw(buffer, data)?.finish()
+    }
+
+    /// Iterate over all comma-separated fields, starting with the address
+    fn raw_fields(&self) -> impl Iterator<Item = &str> {
+        split_fields(&self.raw.as_bytes()[1..self.data_end as usize])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{MessageType, TalkerId};
+    use crate::NmeaParser;
+
+    #[test]
+    fn test_generic_txt_sentence() {
+        let parser = NmeaParser::new();
+        let sentence = b"$GPTXT,01,01,02,ANTSTATUS=OK*3B\r\n";
+
+        let msg = parser.parse_sentence_complete(sentence).unwrap();
+        assert_eq!(msg.message_type(), MessageType::Unknown);
+        assert_eq!(msg.talker_id(), TalkerId::GP);
+
+        let generic = msg.as_unknown().unwrap();
+        assert_eq!(generic.address(), "GPTXT");
+        assert_eq!(generic.sentence_formatter(), "TXT");
+        assert_eq!(generic.field_count(), 4);
+        assert_eq!(generic.field(0), Some("01"));
+        assert_eq!(generic.field(3), Some("ANTSTATUS=OK"));
+        assert_eq!(generic.field(4), None);
+        assert_eq!(generic.as_bytes(), b"$GPTXT,01,01,02,ANTSTATUS=OK*3B");
+    }
+
+    #[test]
+    fn test_generic_empty_fields() {
+        let parser = NmeaParser::new();
+        let sentence = b"$GPXTE,A,,0.67,L,N*2E\r\n";
+
+        let msg = parser.parse_sentence_complete(sentence).unwrap();
+        let generic = msg.as_unknown().unwrap();
+        assert_eq!(generic.field_count(), 5);
+        assert_eq!(generic.field(1), None);
+
+        let mut fields = generic.fields();
+        assert_eq!(fields.next(), Some("A"));
+        assert_eq!(fields.next(), Some(""));
+        assert_eq!(fields.next(), Some("0.67"));
+    }
+
+    #[test]
+    fn test_generic_without_checksum() {
+        let parser = NmeaParser::new();
+        let sentence = b"$IIMTW,18.5,C\r\n";
+
+        let msg = parser.parse_sentence_complete(sentence).unwrap();
+        let generic = msg.as_unknown().unwrap();
+        assert_eq!(msg.talker_id(), TalkerId::II);
+        assert_eq!(generic.sentence_formatter(), "MTW");
+        assert_eq!(generic.field(0), Some("18.5"));
+        assert_eq!(generic.field(1), Some("C"));
+    }
+
+    #[test]
+    fn test_supported_type_is_not_generic() {
+        let parser = NmeaParser::new();
+        let sentence = b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+
+        let msg = parser.parse_sentence_complete(sentence).unwrap();
+        assert!(msg.as_unknown().is_none());
+    }
+}
@@ -16,11 +16,11 @@
 //! |-------|-------|------|-----------|-------------|
 //! | 0 | Sentence ID | String | Yes | Message type (GPGGA, GNGGA, etc.) |
 //! | 1 | UTC Time | String | Yes | hhmmss.ss format |
-//! | 2 | Latitude | f64 | Yes | ddmm.mmmmm format |
+//! | 2 | Latitude | Coordinate | Yes | ddmm.mmmmm format |
 //! | 3 | N/S Indicator | char | Yes | N = North, S = South |
-//! | 4 | Longitude | f64 | Yes | dddmm.mmmmm format |
+//! | 4 | Longitude | Coordinate | Yes | dddmm.mmmmm format |
 //! | 5 | E/W Indicator | char | Yes | E = East, W = West |
-//! | 6 | Fix Quality | u8 | Yes | 0=Invalid, 1=GPS, 2=DGPS, etc. |
+//! | 6 | Fix Quality | FixQuality | Yes | 0=Invalid, 1=GPS, 2=DGPS, 4=RTK fixed, 5=RTK float, etc. |
 //! | 7 | Satellites | u8 | No | Number of satellites in use |
 //! | 8 | HDOP | f32 | No | Horizontal dilution of precision |
 //! | 9 | Altitude | f32 | No | Altitude above mean sea level |
@@ -45,20 +45,26 @@
 //! - Altitude: 545.4 meters above MSL
 //! - Geoid separation: 46.9 meters
 
-use crate::message::ParsedSentence;
-use crate::types::{MessageType, TalkerId};
+use crate::coordinate::{self, Coordinate, Degrees};
+use crate::datetime::NmeaTime;
+use crate::encode::{EncodeError, SentenceWriter};
+use crate::fixed_str::FixedStr;
+use crate::message::{ParsedSentence, MAX_STATION_ID_LEN};
+use crate::types::{ErrorDetail, FixQuality, MessageType, TalkerId};
 
 /// GGA - Global Positioning System Fix Data parameters
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct GgaData {
     pub talker_id: TalkerId,
-    time_data: [u8; 16],
-    time_len: u8,
-    pub latitude: f64,
+    time: NmeaTime,
+    pub latitude: Coordinate,
     pub lat_direction: char,
-    pub longitude: f64,
+    pub longitude: Coordinate,
     pub lon_direction: char,
-    pub fix_quality: u8,
+    pub fix_quality: FixQuality,
+    fix_quality_raw: u8,
     pub num_satellites: Option<u8>,
     pub hdop: Option<f32>,
     pub altitude: Option<f32>,
@@ -66,28 +72,228 @@ pub struct GgaData {
     pub geoid_separation: Option<f32>,
     pub geoid_units: Option<char>,
     pub age_of_diff: Option<f32>,
-    diff_station_id_data: [u8; 8],
-    diff_station_id_len: u8,
+    diff_station_id: FixedStr<MAX_STATION_ID_LEN>,
 }
 
 impl GgaData {
-    /// Get time as string slice
-    pub fn time(&self) -> &str {
-        core::str::from_utf8(&self.time_data[..self.time_len as usize]).unwrap_or("")
+    /// Create a `GgaBuilder` generating GGA sentences
+    pub fn builder() -> GgaBuilder {
+        GgaBuilder::new()
+    }
+
+    /// Get the latitude in signed decimal degrees (negative in the southern hemisphere)
+    ///
+    /// Returns `None` if the direction is neither `N` nor `S`.
+    pub fn latitude_degrees(&self) -> Option<Degrees> {
+        coordinate::latitude_degrees(self.latitude, self.lat_direction)
+    }
+
+    /// Get the longitude in signed decimal degrees (negative in the western hemisphere)
+    ///
+    /// Returns `None` if the direction is neither `E` nor `W`.
+    pub fn longitude_degrees(&self) -> Option<Degrees> {
+        coordinate::longitude_degrees(self.longitude, self.lon_direction)
+    }
+
+    /// Get the latitude in signed units of 1e-7 degrees (`COORDINATE_SCALE`
+    /// units per degree)
+    ///
+    /// Returns `None` if the direction is neither `N` nor `S`.
+    #[cfg(feature = "fixed-point")]
+    pub fn latitude_fixed(&self) -> Option<i32> {
+        self.latitude_degrees().map(coordinate::to_fixed)
+    }
+
+    /// Get the longitude in signed units of 1e-7 degrees (`COORDINATE_SCALE`
+    /// units per degree)
+    ///
+    /// Returns `None` if the direction is neither `E` nor `W`.
+    #[cfg(feature = "fixed-point")]
+    pub fn longitude_fixed(&self) -> Option<i32> {
+        self.longitude_degrees().map(coordinate::to_fixed)
+    }
+
+    /// Get the UTC time
+    pub fn time(&self) -> NmeaTime {
+        self.time
+    }
+
+    /// Get the raw fix quality value as transmitted
+    pub fn fix_quality_raw(&self) -> u8 {
+        self.fix_quality_raw
     }
 
     /// Get differential station ID as string slice (if present)
     pub fn diff_station_id(&self) -> Option<&str> {
-        if self.diff_station_id_len > 0 {
-            core::str::from_utf8(&self.diff_station_id_data[..self.diff_station_id_len as usize])
-                .ok()
-        } else {
-            None
+        self.diff_station_id.as_opt_str()
+    }
+
+    /// Encode as a GGA sentence into `buffer`, returning the sentence length
+    ///
+    /// `fix_quality` is written from its typed value; the raw value is kept for
+    /// `FixQuality::Unknown`.
+    pub fn encode(&self, buffer: &mut [u8]) -> Result<usize, EncodeError> {
+        let mut w = SentenceWriter::new(buffer, self.talker_id, "GGA")?;
+        w.time(self.time)?;
+        w.coordinate(self.latitude, 2)?;
+        w.field(Some(self.lat_direction))?;
+        w.coordinate(self.longitude, 3)?;
+        w.field(Some(self.lon_direction))?;
+        w.field(Some(
+            self.fix_quality.to_u8().unwrap_or(self.fix_quality_raw),
+        ))?;
+        w.field_padded(self.num_satellites, 2)?;
+        w.field(self.hdop)?;
+        w.field(self.altitude)?;
+        w.field(self.altitude_units)?;
+        w.field(self.geoid_separation)?;
+        w.field(self.geoid_units)?;
+        w.field(self.age_of_diff)?;
+        w.field(self.diff_station_id())?;
+        w.finish()
+    }
+}
+
+/// Builder generating GGA sentences from typed values, e.g. for simulation
+///
+/// Unset fields default to midnight UTC, position 0°N 0°E, an invalid fix and
+/// empty optional fields.
+///
+/// # Example
+///
+/// ```
+/// use rustedbytes_nmea::{FixQuality, GgaBuilder, NmeaTime};
+///
+/// let (lat, lon) = (48.1173, 11.516_666_67);
+/// let gga = GgaBuilder::new()
+///     .time(NmeaTime { hour: 12, minute: 35, second: 19, millis: 0 })
+///     .position(lat, lon)
+///     .fix_quality(FixQuality::Gps)
+///     .num_satellites(8)
+///     .hdop(0.9)
+///     .altitude(545.4);
+///
+/// let mut buffer = [0u8; 82];
+/// let len = gga.encode(&mut buffer).unwrap();
+/// assert_eq!(
+///     &buffer[..len],
+///     b"$GPGGA,123519,4807.03800,N,01131.00000,E,1,08,0.9,545.4,M,,,,*1F\r\n"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct GgaBuilder {
+    data: GgaData,
+}
+
+impl GgaBuilder {
+    /// Create a builder for a `$GPGGA` sentence with default values
+    pub fn new() -> Self {
+        GgaBuilder {
+            data: GgaData {
+                talker_id: TalkerId::GP,
+                time: NmeaTime::default(),
+                latitude: Coordinate::default(),
+                lat_direction: 'N',
+                longitude: Coordinate::default(),
+                lon_direction: 'E',
+                fix_quality: FixQuality::Invalid,
+                fix_quality_raw: 0,
+                num_satellites: None,
+                hdop: None,
+                altitude: None,
+                altitude_units: None,
+                geoid_separation: None,
+                geoid_units: None,
+                age_of_diff: None,
+                diff_station_id: FixedStr::new(b""),
+            },
         }
     }
+
+    /// Set the talker ID (default `GP`)
+    pub fn talker_id(mut self, talker_id: TalkerId) -> Self {
+        self.data.talker_id = talker_id;
+        self
+    }
+
+    /// Set the UTC time of the fix
+    pub fn time(mut self, time: NmeaTime) -> Self {
+        self.data.time = time;
+        self
+    }
+
+    /// Set the position in signed decimal degrees (negative south and west)
+    pub fn position(mut self, latitude: Degrees, longitude: Degrees) -> Self {
+        (self.data.latitude, self.data.lat_direction) =
+            coordinate::from_degrees(latitude, 'N', 'S');
+        (self.data.longitude, self.data.lon_direction) =
+            coordinate::from_degrees(longitude, 'E', 'W');
+        self
+    }
+
+    /// Set the fix quality (`FixQuality::Unknown` is written as 0)
+    pub fn fix_quality(mut self, fix_quality: FixQuality) -> Self {
+        self.data.fix_quality_raw = fix_quality.to_u8().unwrap_or(0);
+        self.data.fix_quality = FixQuality::from_u8(self.data.fix_quality_raw);
+        self
+    }
+
+    /// Set the number of satellites in use
+    pub fn num_satellites(mut self, num_satellites: u8) -> Self {
+        self.data.num_satellites = Some(num_satellites);
+        self
+    }
+
+    /// Set the horizontal dilution of precision
+    pub fn hdop(mut self, hdop: f32) -> Self {
+        self.data.hdop = Some(hdop);
+        self
+    }
+
+    /// Set the altitude above mean sea level in meters
+    pub fn altitude(mut self, meters: f32) -> Self {
+        self.data.altitude = Some(meters);
+        self.data.altitude_units = Some('M');
+        self
+    }
+
+    /// Set the geoid separation in meters
+    pub fn geoid_separation(mut self, meters: f32) -> Self {
+        self.data.geoid_separation = Some(meters);
+        self.data.geoid_units = Some('M');
+        self
+    }
+
+    /// Set the age of differential corrections in seconds
+    pub fn age_of_diff(mut self, seconds: f32) -> Self {
+        self.data.age_of_diff = Some(seconds);
+        self
+    }
+
+    /// Set the differential reference station ID (truncated to 4 characters)
+    pub fn diff_station_id(mut self, id: &str) -> Self {
+        self.data.diff_station_id = FixedStr::new(id.as_bytes());
+        self
+    }
+
+    /// Get the GGA data described by the builder
+    pub fn build(self) -> GgaData {
+        self.data
+    }
+
+    /// Encode the sentence into `buffer`, returning the sentence length
+    pub fn encode(&self, buffer: &mut [u8]) -> Result<usize, EncodeError> {
+        self.data.encode(buffer)
+    }
 }
 
-impl ParsedSentence {
+impl Default for GgaBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParsedSentence<'_> {
     /// Extract GGA message parameters
     ///
     /// Parses the GGA (Global Positioning System Fix Data) message and returns
@@ -95,8 +301,8 @@ impl ParsedSentence {
     ///
     /// # Returns
     ///
-    /// - `Some(GgaData)` if the message is a valid GGA message with all mandatory fields
-    /// - `None` if:
+    /// - `Ok(GgaData)` if the message is a valid GGA message with all mandatory fields
+    /// - `Err(ErrorDetail)` with the failing field and reason if:
     ///   - The message is not a GGA message
     ///   - Any mandatory field is missing or invalid
     ///
@@ -107,7 +313,7 @@ impl ParsedSentence {
     /// - Latitude direction (field 3)
     /// - Longitude (field 4)
     /// - Longitude direction (field 5)
-    /// - Fix quality (field 6)
+    /// - Fix quality (field 6) - exposed as `FixQuality`, raw value via `fix_quality_raw()`
     ///
     /// # Optional Fields
     ///
@@ -116,7 +322,7 @@ impl ParsedSentence {
     /// # Example
     ///
     /// ```
-    /// use rustedbytes_nmea::{NmeaParser, MessageType};
+    /// use rustedbytes_nmea::{FixQuality, NmeaParser, MessageType};
     ///
     /// let parser = NmeaParser::new();
     /// let sentence = b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n";
@@ -124,51 +330,34 @@ impl ParsedSentence {
     /// let result = parser.parse_bytes(sentence);
     /// if let Ok((Some(msg), _consumed)) = result {
     ///     if let Some(gga) = msg.as_gga() {
-    ///         assert_eq!(gga.time(), "123519");
+    ///         assert_eq!(gga.time().hour, 12);
     ///         assert_eq!(gga.latitude, 4807.038);
-    ///         assert_eq!(gga.fix_quality, 1);
+    ///         assert_eq!(gga.fix_quality, FixQuality::Gps);
     ///     }
     /// }
     /// ```
-    pub fn as_gga(&self) -> Option<GgaData> {
-        if self.message_type != MessageType::GGA {
-            return None;
-        }
+    pub fn as_gga(&self) -> Result<GgaData, ErrorDetail> {
+        self.expect_type(MessageType::GGA)?;
 
         // Validate mandatory fields
-        let time_str = self.get_field_str(1)?;
-        let latitude: f64 = self.parse_field(2)?;
-        let lat_direction = self.parse_field_char(3)?;
-        let longitude: f64 = self.parse_field(4)?;
-        let lon_direction = self.parse_field_char(5)?;
-        let fix_quality: u8 = self.parse_field(6)?;
-
-        // Copy time string to fixed array
-        let mut time_data = [0u8; 16];
-        let time_bytes = time_str.as_bytes();
-        let time_len = time_bytes.len().min(16) as u8;
-        time_data[..time_len as usize].copy_from_slice(&time_bytes[..time_len as usize]);
-
-        // Copy diff station ID if present
-        let mut diff_station_id_data = [0u8; 8];
-        let diff_station_id_len = if let Some(id_str) = self.get_field_str(14) {
-            let id_bytes = id_str.as_bytes();
-            let len = id_bytes.len().min(8) as u8;
-            diff_station_id_data[..len as usize].copy_from_slice(&id_bytes[..len as usize]);
-            len
-        } else {
-            0
-        };
-
-        Some(GgaData {
+        let time = self.required_time(1)?;
+        let latitude = self.required_coordinate(2)?;
+        let lat_direction = self.required_char(3)?;
+        let longitude = self.required_coordinate(4)?;
+        let lon_direction = self.required_char(5)?;
+        let fix_quality: u8 = self.required_field(6)?;
+
+        let diff_station_id = FixedStr::new(self.raw_field(14).unwrap_or_default());
+
+        Ok(GgaData {
             talker_id: self.talker_id,
-            time_data,
-            time_len,
+            time,
             latitude,
             lat_direction,
             longitude,
             lon_direction,
-            fix_quality,
+            fix_quality: FixQuality::from_u8(fix_quality),
+            fix_quality_raw: fix_quality,
             num_satellites: self.parse_field(7),
             hdop: self.parse_field(8),
             altitude: self.parse_field(9),
@@ -176,14 +365,15 @@ impl ParsedSentence {
             geoid_separation: self.parse_field(11),
             geoid_units: self.parse_field_char(12),
             age_of_diff: self.parse_field(13),
-            diff_station_id_data,
-            diff_station_id_len,
+            diff_station_id,
         })
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::datetime::NmeaTime;
+    use crate::types::FixQuality;
     use crate::NmeaParser;
 
     #[test]
@@ -199,12 +389,20 @@ mod tests {
         assert!(gga.is_some());
 
         let gga_data = gga.unwrap();
-        assert_eq!(gga_data.time(), "123519");
+        assert_eq!(
+            gga_data.time(),
+            NmeaTime {
+                hour: 12,
+                minute: 35,
+                second: 19,
+                millis: 0
+            }
+        );
         assert_eq!(gga_data.latitude, 4807.038);
         assert_eq!(gga_data.lat_direction, 'N');
         assert_eq!(gga_data.longitude, 1131.000);
         assert_eq!(gga_data.lon_direction, 'E');
-        assert_eq!(gga_data.fix_quality, 1);
+        assert_eq!(gga_data.fix_quality, FixQuality::Gps);
         assert_eq!(gga_data.num_satellites, Some(8));
         assert_eq!(gga_data.hdop, Some(0.9));
         assert_eq!(gga_data.altitude, Some(545.4));
@@ -218,7 +416,7 @@ mod tests {
     #[test]
     fn test_gga_with_empty_optional_fields() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGGA,123519,4807.038,N,01131.000,E,1,,,,,M,,M,,*47\r\n";
+        let sentence = b"$GPGGA,123519,4807.038,N,01131.000,E,1,,,,,M,,M,,*7F\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -228,9 +426,17 @@ mod tests {
         assert!(gga.is_some());
 
         let gga_data = gga.unwrap();
-        assert_eq!(gga_data.time(), "123519");
+        assert_eq!(
+            gga_data.time(),
+            NmeaTime {
+                hour: 12,
+                minute: 35,
+                second: 19,
+                millis: 0
+            }
+        );
         assert_eq!(gga_data.latitude, 4807.038);
-        assert_eq!(gga_data.fix_quality, 1);
+        assert_eq!(gga_data.fix_quality, FixQuality::Gps);
         assert_eq!(gga_data.num_satellites, None);
         assert_eq!(gga_data.hdop, None);
         assert_eq!(gga_data.altitude, None);
@@ -239,7 +445,7 @@ mod tests {
     #[test]
     fn test_gga_missing_time() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGGA,,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n";
+        let sentence = b"$GPGGA,,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*4A\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -250,7 +456,7 @@ mod tests {
     #[test]
     fn test_gga_missing_latitude() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGGA,123519,,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n";
+        let sentence = b"$GPGGA,123519,,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*59\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -261,7 +467,7 @@ mod tests {
     #[test]
     fn test_gga_missing_longitude() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGGA,123519,4807.038,N,,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n";
+        let sentence = b"$GPGGA,123519,4807.038,N,,E,1,08,0.9,545.4,M,46.9,M,,*6B\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -272,7 +478,7 @@ mod tests {
     #[test]
     fn test_gga_missing_fix_quality() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGGA,123519,4807.038,N,01131.000,E,,08,0.9,545.4,M,46.9,M,,*47\r\n";
+        let sentence = b"$GPGGA,123519,4807.038,N,01131.000,E,,08,0.9,545.4,M,46.9,M,,*76\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -283,7 +489,7 @@ mod tests {
     #[test]
     fn test_gga_invalid_latitude_format() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGGA,123519,INVALID,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n";
+        let sentence = b"$GPGGA,123519,INVALID,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*08\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -295,7 +501,7 @@ mod tests {
     fn test_gga_with_differential_data() {
         let parser = NmeaParser::new();
         let sentence =
-            b"$GPGGA,123519,4807.038,N,01131.000,E,2,08,0.9,545.4,M,46.9,M,3.2,0120*47\r\n";
+            b"$GPGGA,123519,4807.038,N,01131.000,E,2,08,0.9,545.4,M,46.9,M,3.2,0120*68\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -305,11 +511,42 @@ mod tests {
         assert!(gga.is_some());
 
         let gga_data = gga.unwrap();
-        assert_eq!(gga_data.fix_quality, 2);
+        assert_eq!(gga_data.fix_quality, FixQuality::Dgps);
+        assert_eq!(gga_data.fix_quality_raw(), 2);
         assert_eq!(gga_data.age_of_diff, Some(3.2));
         assert_eq!(gga_data.diff_station_id(), Some("0120"));
     }
 
+    #[test]
+    fn test_gga_rtk_fixed_quality() {
+        let parser = NmeaParser::new();
+        let sentence =
+            b"$GNGGA,123519,4807.038,N,01131.000,E,4,12,0.6,545.4,M,46.9,M,1.0,0031*75\r\n";
+
+        let result = parser.parse_sentence_complete(sentence);
+
+        assert!(result.is_some());
+        let msg = result.unwrap();
+        let gga_data = msg.as_gga().unwrap();
+        assert_eq!(gga_data.fix_quality, FixQuality::RtkFixed);
+        assert_eq!(gga_data.fix_quality_raw(), 4);
+    }
+
+    #[test]
+    fn test_gga_unknown_fix_quality() {
+        let parser = NmeaParser::new();
+        let sentence = b"$GPGGA,123519,4807.038,N,01131.000,E,9,08,0.9,545.4,M,46.9,M,,*4F\r\n";
+
+        let result = parser.parse_sentence_complete(sentence);
+
+        // Unrecognized values are kept and remain accessible as raw values
+        assert!(result.is_some());
+        let msg = result.unwrap();
+        let gga_data = msg.as_gga().unwrap();
+        assert_eq!(gga_data.fix_quality, FixQuality::Unknown);
+        assert_eq!(gga_data.fix_quality_raw(), 9);
+    }
+
     #[test]
     fn test_gga_numeric_precision() {
         let parser = NmeaParser::new();
@@ -339,7 +576,7 @@ mod tests {
     fn test_gga_different_talker_id() {
         let parser = NmeaParser::new();
         // GNGGA is multi-GNSS (GPS + GLONASS + others)
-        let sentence = b"$GNGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n";
+        let sentence = b"$GNGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*59\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -371,7 +608,7 @@ mod tests {
     #[test]
     fn test_gga_glonass_talker_id() {
         let parser = NmeaParser::new();
-        let sentence = b"$GLGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n";
+        let sentence = b"$GLGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*5B\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -387,7 +624,7 @@ mod tests {
     #[test]
     fn test_gga_galileo_talker_id() {
         let parser = NmeaParser::new();
-        let sentence = b"$GAGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n";
+        let sentence = b"$GAGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*56\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -403,7 +640,7 @@ mod tests {
     #[test]
     fn test_gga_beidou_talker_id() {
         let parser = NmeaParser::new();
-        let sentence = b"$GBGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n";
+        let sentence = b"$GBGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*55\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -415,4 +652,69 @@ mod tests {
         let gga_data = gga.unwrap();
         assert_eq!(gga_data.talker_id, crate::types::TalkerId::GB);
     }
+
+    #[test]
+    fn test_gga_builder() {
+        let (lat, lon) = (-22.9068, -43.1729);
+
+        let builder = super::GgaData::builder()
+            .talker_id(crate::types::TalkerId::GN)
+            .time(NmeaTime {
+                hour: 23,
+                minute: 59,
+                second: 59,
+                millis: 500,
+            })
+            .position(lat, lon)
+            .fix_quality(FixQuality::Dgps)
+            .num_satellites(12)
+            .hdop(1.1)
+            .altitude(10.5)
+            .geoid_separation(-5.2)
+            .age_of_diff(3.5)
+            .diff_station_id("0042");
+
+        let mut buffer = [0u8; 82];
+        let len = builder.encode(&mut buffer).unwrap();
+        let sentence = &buffer[..len];
+        assert_eq!(
+            sentence,
+            b"$GNGGA,235959.50,2254.40800,S,04310.37400,W,2,12,1.1,10.5,M,-5.2,M,3.5,0042*72\r\n"
+        );
+
+        let msg = NmeaParser::new().parse_sentence_complete(sentence).unwrap();
+        let gga = msg.as_gga().unwrap();
+        let built = builder.build();
+        assert_eq!(gga.fix_quality, built.fix_quality);
+        assert_eq!(gga.diff_station_id(), Some("0042"));
+        assert_eq!(gga.latitude_degrees(), built.latitude_degrees());
+    }
+
+    #[test]
+    fn test_gga_builder_minutes_carry() {
+        let (lat, lon, expected) = (48.99999999, 11.5, &b"$GPGGA,000000,4900.00000,N,"[..]);
+
+        let mut buffer = [0u8; 82];
+        let len = super::GgaData::builder()
+            .position(lat, lon)
+            .encode(&mut buffer)
+            .unwrap();
+        assert!(buffer[..len].starts_with(expected));
+    }
+
+    #[test]
+    fn test_gga_builder_defaults() {
+        let mut buffer = [0u8; 82];
+        let len = super::GgaBuilder::default().encode(&mut buffer).unwrap();
+        assert_eq!(
+            &buffer[..len],
+            b"$GPGGA,000000,0000.00000,N,00000.00000,E,0,,,,,,,,*5D\r\n"
+        );
+
+        let gga = super::GgaBuilder::new()
+            .fix_quality(FixQuality::Unknown)
+            .build();
+        assert_eq!(gga.fix_quality, FixQuality::Invalid);
+        assert_eq!(gga.fix_quality_raw(), 0);
+    }
 }
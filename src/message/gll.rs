@@ -15,12 +15,12 @@
 //! | Index | Field | Type | Mandatory | Description |
 //! |-------|-------|------|-----------|-------------|
 //! | 0 | Sentence ID | String | Yes | Message type (GPGLL, GNGLL, etc.) |
-//! | 1 | Latitude | f64 | Yes | ddmm.mmmmm format |
+//! | 1 | Latitude | Coordinate | Yes | ddmm.mmmmm format |
 //! | 2 | N/S Indicator | char | Yes | N = North, S = South |
-//! | 3 | Longitude | f64 | Yes | dddmm.mmmmm format |
+//! | 3 | Longitude | Coordinate | Yes | dddmm.mmmmm format |
 //! | 4 | E/W Indicator | char | Yes | E = East, W = West |
 //! | 5 | UTC Time | String | Yes | hhmmss.ss format |
-//! | 6 | Status | char | Yes | A = Valid, V = Invalid |
+//! | 6 | Status | Status | Yes | A = Valid, V = Invalid |
 //!
 //! ## Status Values
 //!
@@ -30,7 +30,7 @@
 //! ## Example
 //!
 //! ```text
-//! $GPGLL,4916.45,N,12311.12,W,225444,A*1D
+//! $GPGLL,4916.45,N,12311.12,W,225444,A*31
 //! ```
 //!
 //! This represents:
@@ -38,30 +38,87 @@
 //! - Time: 22:54:44 UTC
 //! - Status: Active (valid data)
 
+use crate::coordinate::{self, Coordinate, Degrees};
+use crate::datetime::NmeaTime;
+use crate::encode::{EncodeError, SentenceWriter};
 use crate::message::ParsedSentence;
-use crate::types::{MessageType, TalkerId};
+use crate::types::{ErrorDetail, MessageType, Status, TalkerId};
 
 /// GLL - Geographic Position parameters
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct GllData {
     pub talker_id: TalkerId,
-    pub latitude: f64,
+    pub latitude: Coordinate,
     pub lat_direction: char,
-    pub longitude: f64,
+    pub longitude: Coordinate,
     pub lon_direction: char,
-    time_data: [u8; 16],
-    time_len: u8,
-    pub status: char,
+    time: NmeaTime,
+    pub status: Status,
+    status_raw: char,
 }
 
 impl GllData {
-    /// Get time as string slice
-    pub fn time(&self) -> &str {
-        core::str::from_utf8(&self.time_data[..self.time_len as usize]).unwrap_or("")
+    /// Get the latitude in signed decimal degrees (negative in the southern hemisphere)
+    ///
+    /// Returns `None` if the direction is neither `N` nor `S`.
+    pub fn latitude_degrees(&self) -> Option<Degrees> {
+        coordinate::latitude_degrees(self.latitude, self.lat_direction)
+    }
+
+    /// Get the longitude in signed decimal degrees (negative in the western hemisphere)
+    ///
+    /// Returns `None` if the direction is neither `E` nor `W`.
+    pub fn longitude_degrees(&self) -> Option<Degrees> {
+        coordinate::longitude_degrees(self.longitude, self.lon_direction)
+    }
+
+    /// Get the latitude in signed units of 1e-7 degrees (`COORDINATE_SCALE`
+    /// units per degree)
+    ///
+    /// Returns `None` if the direction is neither `N` nor `S`.
+    #[cfg(feature = "fixed-point")]
+    pub fn latitude_fixed(&self) -> Option<i32> {
+        self.latitude_degrees().map(coordinate::to_fixed)
+    }
+
+    /// Get the longitude in signed units of 1e-7 degrees (`COORDINATE_SCALE`
+    /// units per degree)
+    ///
+    /// Returns `None` if the direction is neither `E` nor `W`.
+    #[cfg(feature = "fixed-point")]
+    pub fn longitude_fixed(&self) -> Option<i32> {
+        self.longitude_degrees().map(coordinate::to_fixed)
+    }
+
+    /// Get the UTC time
+    pub fn time(&self) -> NmeaTime {
+        self.time
+    }
+
+    /// Get the raw status character as transmitted
+    pub fn status_raw(&self) -> char {
+        self.status_raw
+    }
+
+    /// Encode as a GLL sentence into `buffer`, returning the sentence length
+    ///
+    /// `status` is written from its typed value; the raw value is kept for
+    /// `Status::Unknown`.
+    pub fn encode(&self, buffer: &mut [u8]) -> Result<usize, EncodeError> {
+        let mut w = SentenceWriter::new(buffer, self.talker_id, "GLL")?;
+        w.coordinate(self.latitude, 2)?;
+        w.field(Some(self.lat_direction))?;
+        w.coordinate(self.longitude, 3)?;
+        w.field(Some(self.lon_direction))?;
+        w.time(self.time)?;
+        w.field(Some(self.status.to_char().unwrap_or(self.status_raw)))?;
+        w.finish()
     }
 }
 
-impl ParsedSentence {
+impl ParsedSentence<'_> {
     /// Extract GLL message parameters
     ///
     /// Parses the GLL (Geographic Position) message and returns a structured
@@ -69,8 +126,8 @@ impl ParsedSentence {
     ///
     /// # Returns
     ///
-    /// - `Some(GllData)` if the message is a valid GLL message with all mandatory fields
-    /// - `None` if:
+    /// - `Ok(GllData)` if the message is a valid GLL message with all mandatory fields
+    /// - `Err(ErrorDetail)` with the failing field and reason if:
     ///   - The message is not a GLL message
     ///   - Any mandatory field is missing or invalid
     ///
@@ -87,59 +144,53 @@ impl ParsedSentence {
     /// # Example
     ///
     /// ```
-    /// use rustedbytes_nmea::{NmeaParser, MessageType};
+    /// use rustedbytes_nmea::{NmeaParser, MessageType, Status};
     ///
     /// let parser = NmeaParser::new();
-    /// let sentence = b"$GPGLL,4916.45,N,12311.12,W,225444,A*1D\r\n";
+    /// let sentence = b"$GPGLL,4916.45,N,12311.12,W,225444,A*31\r\n";
     ///
     /// let result = parser.parse_bytes(sentence);
     /// if let Ok((Some(msg), _consumed)) = result {
     ///     if let Some(gll) = msg.as_gll() {
     ///         assert_eq!(gll.latitude, 4916.45);
-    ///         assert_eq!(gll.status, 'A');
+    ///         assert_eq!(gll.status, Status::Valid);
     ///     }
     /// }
     /// ```
-    pub fn as_gll(&self) -> Option<GllData> {
-        if self.message_type != MessageType::GLL {
-            return None;
-        }
+    pub fn as_gll(&self) -> Result<GllData, ErrorDetail> {
+        self.expect_type(MessageType::GLL)?;
 
         // Validate mandatory fields
-        let latitude: f64 = self.parse_field(1)?;
-        let lat_direction = self.parse_field_char(2)?;
-        let longitude: f64 = self.parse_field(3)?;
-        let lon_direction = self.parse_field_char(4)?;
-        let time_str = self.get_field_str(5)?;
-        let status = self.parse_field_char(6)?;
-
-        // Copy time to fixed array
-        let mut time_data = [0u8; 16];
-        let time_bytes = time_str.as_bytes();
-        let time_len = time_bytes.len().min(16) as u8;
-        time_data[..time_len as usize].copy_from_slice(&time_bytes[..time_len as usize]);
-
-        Some(GllData {
+        let latitude = self.required_coordinate(1)?;
+        let lat_direction = self.required_char(2)?;
+        let longitude = self.required_coordinate(3)?;
+        let lon_direction = self.required_char(4)?;
+        let time = self.required_time(5)?;
+        let status = self.required_char(6)?;
+
+        Ok(GllData {
             talker_id: self.talker_id,
             latitude,
             lat_direction,
             longitude,
             lon_direction,
-            time_data,
-            time_len,
-            status,
+            time,
+            status: Status::from_char(status),
+            status_raw: status,
         })
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::datetime::NmeaTime;
+    use crate::types::Status;
     use crate::NmeaParser;
 
     #[test]
     fn test_gll_complete_message() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGLL,4916.45,N,12311.12,W,225444,A*1D\r\n";
+        let sentence = b"$GPGLL,4916.45,N,12311.12,W,225444,A*31\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -153,14 +204,22 @@ mod tests {
         assert_eq!(gll_data.lat_direction, 'N');
         assert_eq!(gll_data.longitude, 12311.12);
         assert_eq!(gll_data.lon_direction, 'W');
-        assert_eq!(gll_data.time(), "225444");
-        assert_eq!(gll_data.status, 'A');
+        assert_eq!(
+            gll_data.time(),
+            NmeaTime {
+                hour: 22,
+                minute: 54,
+                second: 44,
+                millis: 0
+            }
+        );
+        assert_eq!(gll_data.status, Status::Valid);
     }
 
     #[test]
     fn test_gll_void_status() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGLL,4916.45,N,12311.12,W,225444,V*1D\r\n";
+        let sentence = b"$GPGLL,4916.45,N,12311.12,W,225444,V*26\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -170,13 +229,28 @@ mod tests {
         assert!(gll.is_some());
 
         let gll_data = gll.unwrap();
-        assert_eq!(gll_data.status, 'V');
+        assert_eq!(gll_data.status, Status::Invalid);
+        assert_eq!(gll_data.status_raw(), 'V');
+    }
+
+    #[test]
+    fn test_gll_unknown_status() {
+        let parser = NmeaParser::new();
+        let sentence = b"$GPGLL,4916.45,N,12311.12,W,225444,X*28\r\n";
+
+        let result = parser.parse_sentence_complete(sentence);
+
+        assert!(result.is_some());
+        let msg = result.unwrap();
+        let gll_data = msg.as_gll().unwrap();
+        assert_eq!(gll_data.status, Status::Unknown);
+        assert_eq!(gll_data.status_raw(), 'X');
     }
 
     #[test]
     fn test_gll_south_east() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGLL,3723.2475,S,14507.3647,E,225444,A*1D\r\n";
+        let sentence = b"$GPGLL,3723.2475,S,14507.3647,E,225444,A*36\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -193,7 +267,7 @@ mod tests {
     #[test]
     fn test_gll_missing_latitude() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGLL,,N,12311.12,W,225444,A*1D\r\n";
+        let sentence = b"$GPGLL,,N,12311.12,W,225444,A*14\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -204,7 +278,7 @@ mod tests {
     #[test]
     fn test_gll_missing_longitude() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGLL,4916.45,N,,W,225444,A*1D\r\n";
+        let sentence = b"$GPGLL,4916.45,N,,W,225444,A*2C\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -215,7 +289,7 @@ mod tests {
     #[test]
     fn test_gll_missing_time() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGLL,4916.45,N,12311.12,W,,A*1D\r\n";
+        let sentence = b"$GPGLL,4916.45,N,12311.12,W,,A*30\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -226,7 +300,7 @@ mod tests {
     #[test]
     fn test_gll_missing_status() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGLL,4916.45,N,12311.12,W,225444,*1D\r\n";
+        let sentence = b"$GPGLL,4916.45,N,12311.12,W,225444,*70\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -237,7 +311,7 @@ mod tests {
     #[test]
     fn test_gll_invalid_latitude() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGLL,INVALID,N,12311.12,W,225444,A*1D\r\n";
+        let sentence = b"$GPGLL,INVALID,N,12311.12,W,225444,A*45\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -248,7 +322,7 @@ mod tests {
     #[test]
     fn test_gll_numeric_precision() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGLL,4916.45,N,12311.12,W,225444,A*1D\r\n";
+        let sentence = b"$GPGLL,4916.45,N,12311.12,W,225444,A*31\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -265,7 +339,7 @@ mod tests {
     #[test]
     fn test_gll_high_precision_coordinates() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGLL,4916.453789,N,12311.125678,W,225444,A*1D\r\n";
+        let sentence = b"$GPGLL,4916.453789,N,12311.125678,W,225444,A*38\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -283,7 +357,7 @@ mod tests {
     fn test_gll_different_talker_id() {
         let parser = NmeaParser::new();
         // GNGLL is multi-GNSS
-        let sentence = b"$GNGLL,4916.45,N,12311.12,W,225444,A*1D\r\n";
+        let sentence = b"$GNGLL,4916.45,N,12311.12,W,225444,A*2F\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -301,7 +375,7 @@ mod tests {
         let parser = NmeaParser::new();
 
         // Test GPS
-        let gp_sentence = b"$GPGLL,4916.45,N,12311.12,W,225444,A*1D\r\n";
+        let gp_sentence = b"$GPGLL,4916.45,N,12311.12,W,225444,A*31\r\n";
         let gp_result = parser.parse_sentence_complete(gp_sentence);
         assert!(gp_result.is_some());
         let gp_msg = gp_result.unwrap();
@@ -309,7 +383,7 @@ mod tests {
         assert_eq!(gp_gll.talker_id, crate::types::TalkerId::GP);
 
         // Test BeiDou (GB)
-        let gb_sentence = b"$GBGLL,4916.45,N,12311.12,W,225444,A*1D\r\n";
+        let gb_sentence = b"$GBGLL,4916.45,N,12311.12,W,225444,A*23\r\n";
         let gb_result = parser.parse_sentence_complete(gb_sentence);
         assert!(gb_result.is_some());
         let gb_msg = gb_result.unwrap();
@@ -320,7 +394,7 @@ mod tests {
     #[test]
     fn test_gll_time_with_decimals() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGLL,4916.45,N,12311.12,W,225444.50,A*1D\r\n";
+        let sentence = b"$GPGLL,4916.45,N,12311.12,W,225444.50,A*1A\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -330,6 +404,14 @@ mod tests {
         assert!(gll.is_some());
 
         let gll_data = gll.unwrap();
-        assert_eq!(gll_data.time(), "225444.50");
+        assert_eq!(
+            gll_data.time(),
+            NmeaTime {
+                hour: 22,
+                minute: 54,
+                second: 44,
+                millis: 500
+            }
+        );
     }
 }
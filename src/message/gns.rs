@@ -16,9 +16,9 @@
 //! |-------|-------|------|-----------|-------------|
 //! | 0 | Sentence ID | String | Yes | Message type (GPGNS, GNGNS, etc.) |
 //! | 1 | UTC Time | String | Yes | hhmmss.ss format |
-//! | 2 | Latitude | f64 | Yes | ddmm.mmmmm format |
+//! | 2 | Latitude | Coordinate | Yes | ddmm.mmmmm format |
 //! | 3 | N/S Indicator | char | Yes | N = North, S = South |
-//! | 4 | Longitude | f64 | Yes | dddmm.mmmmm format |
+//! | 4 | Longitude | Coordinate | Yes | dddmm.mmmmm format |
 //! | 5 | E/W Indicator | char | Yes | E = East, W = West |
 //! | 6 | Mode Indicator | String | Yes | Position fix mode for each GNSS |
 //! | 7 | Satellites | u8 | Yes | Number of satellites in use |
@@ -46,7 +46,7 @@
 //! ## Example
 //!
 //! ```text
-//! $GPGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*79
+//! $GPGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*69
 //! ```
 //!
 //! This represents:
@@ -58,55 +58,105 @@
 //! - Altitude: 1005.543 meters above MSL
 //! - Geoid separation: 6.5 meters
 
-use crate::message::ParsedSentence;
-use crate::types::{MessageType, TalkerId};
+use crate::coordinate::{self, Coordinate, Degrees};
+use crate::datetime::NmeaTime;
+use crate::encode::{EncodeError, SentenceWriter};
+use crate::fixed_str::FixedStr;
+use crate::message::{ParsedSentence, MAX_MODE_LEN, MAX_STATION_ID_LEN};
+use crate::types::{ErrorDetail, MessageType, TalkerId};
 
 /// GNS - GNSS Fix Data parameters
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct GnsData {
     pub talker_id: TalkerId,
-    time_data: [u8; 16],
-    time_len: u8,
-    pub latitude: f64,
+    time: NmeaTime,
+    pub latitude: Coordinate,
     pub lat_direction: char,
-    pub longitude: f64,
+    pub longitude: Coordinate,
     pub lon_direction: char,
-    mode_indicator_data: [u8; 8],
-    mode_indicator_len: u8,
+    mode_indicator: FixedStr<MAX_MODE_LEN>,
     pub num_satellites: u8,
     pub hdop: Option<f32>,
     pub altitude: Option<f32>,
     pub geoid_separation: Option<f32>,
     pub age_of_diff: Option<f32>,
-    diff_station_id_data: [u8; 8],
-    diff_station_id_len: u8,
+    diff_station_id: FixedStr<MAX_STATION_ID_LEN>,
     pub nav_status: Option<char>,
 }
 
 impl GnsData {
-    /// Get time as string slice
-    pub fn time(&self) -> &str {
-        core::str::from_utf8(&self.time_data[..self.time_len as usize]).unwrap_or("")
+    /// Get the latitude in signed decimal degrees (negative in the southern hemisphere)
+    ///
+    /// Returns `None` if the direction is neither `N` nor `S`.
+    pub fn latitude_degrees(&self) -> Option<Degrees> {
+        coordinate::latitude_degrees(self.latitude, self.lat_direction)
+    }
+
+    /// Get the longitude in signed decimal degrees (negative in the western hemisphere)
+    ///
+    /// Returns `None` if the direction is neither `E` nor `W`.
+    pub fn longitude_degrees(&self) -> Option<Degrees> {
+        coordinate::longitude_degrees(self.longitude, self.lon_direction)
+    }
+
+    /// Get the latitude in signed units of 1e-7 degrees (`COORDINATE_SCALE`
+    /// units per degree)
+    ///
+    /// Returns `None` if the direction is neither `N` nor `S`.
+    #[cfg(feature = "fixed-point")]
+    pub fn latitude_fixed(&self) -> Option<i32> {
+        self.latitude_degrees().map(coordinate::to_fixed)
+    }
+
+    /// Get the longitude in signed units of 1e-7 degrees (`COORDINATE_SCALE`
+    /// units per degree)
+    ///
+    /// Returns `None` if the direction is neither `E` nor `W`.
+    #[cfg(feature = "fixed-point")]
+    pub fn longitude_fixed(&self) -> Option<i32> {
+        self.longitude_degrees().map(coordinate::to_fixed)
+    }
+
+    /// Get the UTC time
+    pub fn time(&self) -> NmeaTime {
+        self.time
     }
 
     /// Get mode indicator as string slice
     pub fn mode_indicator(&self) -> &str {
-        core::str::from_utf8(&self.mode_indicator_data[..self.mode_indicator_len as usize])
-            .unwrap_or("")
+        self.mode_indicator.as_str()
     }
 
     /// Get differential station ID as string slice (if present)
     pub fn diff_station_id(&self) -> Option<&str> {
-        if self.diff_station_id_len > 0 {
-            core::str::from_utf8(&self.diff_station_id_data[..self.diff_station_id_len as usize])
-                .ok()
-        } else {
-            None
+        self.diff_station_id.as_opt_str()
+    }
+
+    /// Encode as a GNS sentence into `buffer`, returning the sentence length
+    pub fn encode(&self, buffer: &mut [u8]) -> Result<usize, EncodeError> {
+        let mut w = SentenceWriter::new(buffer, self.talker_id, "GNS")?;
+        w.time(self.time)?;
+        w.coordinate(self.latitude, 2)?;
+        w.field(Some(self.lat_direction))?;
+        w.coordinate(self.longitude, 3)?;
+        w.field(Some(self.lon_direction))?;
+        w.field(Some(self.mode_indicator()))?;
+        w.field_padded(Some(self.num_satellites), 2)?;
+        w.field(self.hdop)?;
+        w.field(self.altitude)?;
+        w.field(self.geoid_separation)?;
+        w.field(self.age_of_diff)?;
+        w.field(self.diff_station_id())?;
+        if let Some(nav_status) = self.nav_status {
+            w.field(Some(nav_status))?;
         }
+        w.finish()
     }
 }
 
-impl ParsedSentence {
+impl ParsedSentence<'_> {
     /// Extract GNS message parameters
     ///
     /// Parses the GNS (GNSS Fix Data) message and returns a structured
@@ -114,8 +164,8 @@ impl ParsedSentence {
     ///
     /// # Returns
     ///
-    /// - `Some(GnsData)` if the message is a valid GNS message with all mandatory fields
-    /// - `None` if:
+    /// - `Ok(GnsData)` if the message is a valid GNS message with all mandatory fields
+    /// - `Err(ErrorDetail)` with the failing field and reason if:
     ///   - The message is not a GNS message
     ///   - Any mandatory field is missing or invalid
     ///
@@ -139,72 +189,45 @@ impl ParsedSentence {
     /// use rustedbytes_nmea::{NmeaParser, MessageType};
     ///
     /// let parser = NmeaParser::new();
-    /// let sentence = b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*79\r\n";
+    /// let sentence = b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*69\r\n";
     ///
     /// let result = parser.parse_bytes(sentence);
     /// if let Ok((Some(msg), _consumed)) = result {
     ///     if let Some(gns) = msg.as_gns() {
-    ///         assert_eq!(gns.time(), "122310.0");
+    ///         assert_eq!(gns.time().hour, 12);
     ///         assert_eq!(gns.latitude, 3723.46587);
     ///         assert_eq!(gns.num_satellites, 12);
     ///     }
     /// }
     /// ```
-    pub fn as_gns(&self) -> Option<GnsData> {
-        if self.message_type != MessageType::GNS {
-            return None;
-        }
+    pub fn as_gns(&self) -> Result<GnsData, ErrorDetail> {
+        self.expect_type(MessageType::GNS)?;
 
         // Validate mandatory fields
-        let time_str = self.get_field_str(1)?;
-        let latitude: f64 = self.parse_field(2)?;
-        let lat_direction = self.parse_field_char(3)?;
-        let longitude: f64 = self.parse_field(4)?;
-        let lon_direction = self.parse_field_char(5)?;
-        let mode_indicator_str = self.get_field_str(6)?;
-        let num_satellites: u8 = self.parse_field(7)?;
-
-        // Copy time string to fixed array
-        let mut time_data = [0u8; 16];
-        let time_bytes = time_str.as_bytes();
-        let time_len = time_bytes.len().min(16) as u8;
-        time_data[..time_len as usize].copy_from_slice(&time_bytes[..time_len as usize]);
-
-        // Copy mode indicator to fixed array
-        let mut mode_indicator_data = [0u8; 8];
-        let mode_bytes = mode_indicator_str.as_bytes();
-        let mode_indicator_len = mode_bytes.len().min(8) as u8;
-        mode_indicator_data[..mode_indicator_len as usize]
-            .copy_from_slice(&mode_bytes[..mode_indicator_len as usize]);
-
-        // Copy diff station ID if present
-        let mut diff_station_id_data = [0u8; 8];
-        let diff_station_id_len = if let Some(id_str) = self.get_field_str(12) {
-            let id_bytes = id_str.as_bytes();
-            let len = id_bytes.len().min(8) as u8;
-            diff_station_id_data[..len as usize].copy_from_slice(&id_bytes[..len as usize]);
-            len
-        } else {
-            0
-        };
-
-        Some(GnsData {
+        let time = self.required_time(1)?;
+        let latitude = self.required_coordinate(2)?;
+        let lat_direction = self.required_char(3)?;
+        let longitude = self.required_coordinate(4)?;
+        let lon_direction = self.required_char(5)?;
+        let mode_indicator = FixedStr::new(self.required_str(6)?.as_bytes());
+        let num_satellites: u8 = self.required_field(7)?;
+
+        let diff_station_id = FixedStr::new(self.raw_field(12).unwrap_or_default());
+
+        Ok(GnsData {
             talker_id: self.talker_id,
-            time_data,
-            time_len,
+            time,
             latitude,
             lat_direction,
             longitude,
             lon_direction,
-            mode_indicator_data,
-            mode_indicator_len,
+            mode_indicator,
             num_satellites,
             hdop: self.parse_field(8),
             altitude: self.parse_field(9),
             geoid_separation: self.parse_field(10),
             age_of_diff: self.parse_field(11),
-            diff_station_id_data,
-            diff_station_id_len,
+            diff_station_id,
             nav_status: self.parse_field_char(13),
         })
     }
@@ -212,13 +235,14 @@ impl ParsedSentence {
 
 #[cfg(test)]
 mod tests {
+    use crate::datetime::NmeaTime;
     use crate::NmeaParser;
 
     #[test]
     fn test_gns_complete_message() {
         let parser = NmeaParser::new();
         let sentence =
-            b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*79\r\n";
+            b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*69\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -228,7 +252,15 @@ mod tests {
         assert!(gns.is_some());
 
         let gns_data = gns.unwrap();
-        assert_eq!(gns_data.time(), "122310.0");
+        assert_eq!(
+            gns_data.time(),
+            NmeaTime {
+                hour: 12,
+                minute: 23,
+                second: 10,
+                millis: 0
+            }
+        );
         assert_eq!(gns_data.latitude, 3723.46587);
         assert_eq!(gns_data.lat_direction, 'N');
         assert_eq!(gns_data.longitude, 12202.26957);
@@ -246,7 +278,7 @@ mod tests {
     #[test]
     fn test_gns_with_empty_optional_fields() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,,,,,*79\r\n";
+        let sentence = b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,,,,,*7B\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -256,7 +288,15 @@ mod tests {
         assert!(gns.is_some());
 
         let gns_data = gns.unwrap();
-        assert_eq!(gns_data.time(), "122310.0");
+        assert_eq!(
+            gns_data.time(),
+            NmeaTime {
+                hour: 12,
+                minute: 23,
+                second: 10,
+                millis: 0
+            }
+        );
         assert_eq!(gns_data.latitude, 3723.46587);
         assert_eq!(gns_data.num_satellites, 12);
         assert_eq!(gns_data.hdop, None);
@@ -267,7 +307,7 @@ mod tests {
     #[test]
     fn test_gns_missing_time() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGNS,,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*79\r\n";
+        let sentence = b"$GPGNS,,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*74\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -278,7 +318,7 @@ mod tests {
     #[test]
     fn test_gns_missing_latitude() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGNS,122310.0,,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*79\r\n";
+        let sentence = b"$GPGNS,122310.0,,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*7A\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -289,7 +329,7 @@ mod tests {
     #[test]
     fn test_gns_missing_mode_indicator() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,,12,0.9,1005.543,6.5,,*79\r\n";
+        let sentence = b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,,12,0.9,1005.543,6.5,,*69\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -301,7 +341,7 @@ mod tests {
     fn test_gns_missing_num_satellites() {
         let parser = NmeaParser::new();
         let sentence =
-            b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,,0.9,1005.543,6.5,,*79\r\n";
+            b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,,0.9,1005.543,6.5,,*6A\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -313,7 +353,7 @@ mod tests {
     fn test_gns_with_differential_data() {
         let parser = NmeaParser::new();
         let sentence =
-            b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,DDDD,12,0.9,1005.543,6.5,2.5,0120*79\r\n";
+            b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,DDDD,12,0.9,1005.543,6.5,2.5,0120*43\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -332,7 +372,7 @@ mod tests {
     fn test_gns_with_nav_status() {
         let parser = NmeaParser::new();
         let sentence =
-            b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,,V*79\r\n";
+            b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,,V*13\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -349,7 +389,7 @@ mod tests {
     fn test_gns_numeric_precision() {
         let parser = NmeaParser::new();
         let sentence =
-            b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*79\r\n";
+            b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*69\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -376,7 +416,7 @@ mod tests {
         let parser = NmeaParser::new();
         // GNGNS is multi-GNSS (GPS + GLONASS + others)
         let sentence =
-            b"$GNGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*79\r\n";
+            b"$GNGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*77\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -393,7 +433,7 @@ mod tests {
     fn test_gns_gps_talker_id() {
         let parser = NmeaParser::new();
         let sentence =
-            b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*79\r\n";
+            b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*69\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -412,7 +452,7 @@ mod tests {
 
         // Test GPS
         let gp_sentence =
-            b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*79\r\n";
+            b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*69\r\n";
         let gp_result = parser.parse_sentence_complete(gp_sentence);
         assert!(gp_result.is_some());
         let gp_msg = gp_result.unwrap();
@@ -421,7 +461,7 @@ mod tests {
 
         // Test GLONASS
         let gl_sentence =
-            b"$GLGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*79\r\n";
+            b"$GLGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*75\r\n";
         let gl_result = parser.parse_sentence_complete(gl_sentence);
         assert!(gl_result.is_some());
         let gl_msg = gl_result.unwrap();
@@ -430,7 +470,7 @@ mod tests {
 
         // Test Galileo
         let ga_sentence =
-            b"$GAGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*79\r\n";
+            b"$GAGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*78\r\n";
         let ga_result = parser.parse_sentence_complete(ga_sentence);
         assert!(ga_result.is_some());
         let ga_msg = ga_result.unwrap();
@@ -444,7 +484,7 @@ mod tests {
 
         // Test autonomous mode
         let sentence =
-            b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*79\r\n";
+            b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,AAAA,12,0.9,1005.543,6.5,,*69\r\n";
         let result = parser.parse_sentence_complete(sentence);
         assert!(result.is_some());
         let msg = result.unwrap();
@@ -453,7 +493,7 @@ mod tests {
 
         // Test differential mode
         let sentence =
-            b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,DDDD,12,0.9,1005.543,6.5,,*79\r\n";
+            b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,DDDD,12,0.9,1005.543,6.5,,*69\r\n";
         let result = parser.parse_sentence_complete(sentence);
         assert!(result.is_some());
         let msg = result.unwrap();
@@ -462,7 +502,7 @@ mod tests {
 
         // Test no fix mode
         let sentence =
-            b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,NNNN,12,0.9,1005.543,6.5,,*79\r\n";
+            b"$GPGNS,122310.0,3723.46587,N,12202.26957,W,NNNN,12,0.9,1005.543,6.5,,*69\r\n";
         let result = parser.parse_sentence_complete(sentence);
         assert!(result.is_some());
         let msg = result.unwrap();
@@ -16,11 +16,23 @@
 //! |-------|-------|------|-----------|-------------|
 //! | 0 | Sentence ID | String | Yes | Message type (GPGSA, GNGSA, etc.) |
 //! | 1 | Mode | char | Yes | M = Manual, A = Automatic |
-//! | 2 | Fix Type | u8 | Yes | 1=No fix, 2=2D, 3=3D |
+//! | 2 | Fix Type | FixType | Yes | 1=No fix, 2=2D, 3=3D |
 //! | 3-14 | Satellite IDs | u8 | No | PRNs of satellites used (up to 12) |
 //! | 15 | PDOP | f32 | No | Position dilution of precision |
 //! | 16 | HDOP | f32 | No | Horizontal dilution of precision |
 //! | 17 | VDOP | f32 | No | Vertical dilution of precision |
+//! | 18 | System ID | hex | No | GNSS system ID (NMEA 4.11+) |
+//!
+//! ## System ID
+//!
+//! NMEA 4.11 receivers emit one `$GNGSA` sentence per constellation and append
+//! a system ID so each sentence can be attributed:
+//! - 1 = GPS
+//! - 2 = GLONASS
+//! - 3 = Galileo
+//! - 4 = BeiDou
+//! - 5 = QZSS
+//! - 6 = NavIC (IRNSS)
 //!
 //! ## DOP Values
 //!
@@ -51,22 +63,63 @@
 //! - HDOP: 1.3
 //! - VDOP: 2.1
 
+use crate::encode::{EncodeError, SentenceWriter};
 use crate::message::ParsedSentence;
-use crate::types::{MessageType, TalkerId};
+use crate::types::{ErrorDetail, FixType, MessageType, SystemId, TalkerId};
 
 /// GSA - GPS DOP and active satellites parameters
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct GsaData {
     pub talker_id: TalkerId,
     pub mode: char,
-    pub fix_type: u8,
+    pub fix_type: FixType,
+    fix_type_raw: u8,
     pub satellite_ids: [Option<u8>; 12],
     pub pdop: Option<f32>,
     pub hdop: Option<f32>,
     pub vdop: Option<f32>,
+    pub system_id: Option<SystemId>,
+    system_id_raw: Option<u8>,
 }
 
-impl ParsedSentence {
+impl GsaData {
+    /// Get the raw fix type value as transmitted
+    pub fn fix_type_raw(&self) -> u8 {
+        self.fix_type_raw
+    }
+
+    /// Get the raw NMEA 4.11 System ID as transmitted
+    pub fn system_id_raw(&self) -> Option<u8> {
+        self.system_id_raw
+    }
+
+    /// Encode as a GSA sentence into `buffer`, returning the sentence length
+    ///
+    /// `fix_type` and `system_id` are written from their typed values; the raw
+    /// values are kept for `FixType::Unknown` and `SystemId::Unknown`.
+    pub fn encode(&self, buffer: &mut [u8]) -> Result<usize, EncodeError> {
+        let mut w = SentenceWriter::new(buffer, self.talker_id, "GSA")?;
+        w.field(Some(self.mode))?;
+        w.field(Some(self.fix_type.to_u8().unwrap_or(self.fix_type_raw)))?;
+        for id in self.satellite_ids {
+            w.field_padded(id, 2)?;
+        }
+        w.field(self.pdop)?;
+        w.field(self.hdop)?;
+        w.field(self.vdop)?;
+        if let Some(system_id) = self.system_id {
+            // Without a raw value, unknown systems are written as 0, which
+            // parses back as `Unknown`
+            let id = system_id.id().or(self.system_id_raw).unwrap_or(0);
+            w.field_fmt(format_args!("{:X}", id))?;
+        }
+        w.finish()
+    }
+}
+
+impl ParsedSentence<'_> {
     /// Extract GSA message parameters
     ///
     /// Parses the GSA (GPS DOP and Active Satellites) message and returns
@@ -74,25 +127,26 @@ impl ParsedSentence {
     ///
     /// # Returns
     ///
-    /// - `Some(GsaData)` if the message is a valid GSA message with all mandatory fields
-    /// - `None` if:
+    /// - `Ok(GsaData)` if the message is a valid GSA message with all mandatory fields
+    /// - `Err(ErrorDetail)` with the failing field and reason if:
     ///   - The message is not a GSA message
     ///   - Any mandatory field is missing or invalid
     ///
     /// # Mandatory Fields
     ///
     /// - Mode (field 1) - 'M' for manual, 'A' for automatic
-    /// - Fix type (field 2) - 1 for no fix, 2 for 2D, 3 for 3D
+    /// - Fix type (field 2) - 1 for no fix, 2 for 2D, 3 for 3D, exposed as `FixType`
     ///
     /// # Optional Fields
     ///
     /// - Satellite IDs (fields 3-14) - up to 12 satellite PRNs
     /// - PDOP, HDOP, VDOP (fields 15-17)
+    /// - System ID (field 18) - NMEA 4.11 GNSS system identifier
     ///
     /// # Example
     ///
     /// ```
-    /// use rustedbytes_nmea::{NmeaParser, MessageType};
+    /// use rustedbytes_nmea::{FixType, NmeaParser, MessageType};
     ///
     /// let parser = NmeaParser::new();
     /// let sentence = b"$GPGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39\r\n";
@@ -101,24 +155,24 @@ impl ParsedSentence {
     /// if let Ok((Some(msg), _consumed)) = result {
     ///     if let Some(gsa) = msg.as_gsa() {
     ///         assert_eq!(gsa.mode, 'A');
-    ///         assert_eq!(gsa.fix_type, 3);
+    ///         assert_eq!(gsa.fix_type, FixType::Fix3D);
     ///         assert_eq!(gsa.satellite_ids[0], Some(4));
     ///     }
     /// }
     /// ```
-    pub fn as_gsa(&self) -> Option<GsaData> {
-        if self.message_type != MessageType::GSA {
-            return None;
-        }
+    pub fn as_gsa(&self) -> Result<GsaData, ErrorDetail> {
+        self.expect_type(MessageType::GSA)?;
 
         // Validate mandatory fields
-        let mode = self.parse_field_char(1)?;
-        let fix_type: u8 = self.parse_field(2)?;
+        let mode = self.required_char(1)?;
+        let fix_type: u8 = self.required_field(2)?;
+        let system_id = self.parse_field_hex(18);
 
-        Some(GsaData {
+        Ok(GsaData {
             talker_id: self.talker_id,
             mode,
-            fix_type,
+            fix_type: FixType::from_u8(fix_type),
+            fix_type_raw: fix_type,
             satellite_ids: [
                 self.parse_field(3),
                 self.parse_field(4),
@@ -136,12 +190,15 @@ impl ParsedSentence {
             pdop: self.parse_field(15),
             hdop: self.parse_field(16),
             vdop: self.parse_field(17),
+            system_id: system_id.map(SystemId::from_id),
+            system_id_raw: system_id,
         })
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::types::{FixType, SystemId};
     use crate::NmeaParser;
 
     #[test]
@@ -158,7 +215,7 @@ mod tests {
 
         let gsa_data = gsa.unwrap();
         assert_eq!(gsa_data.mode, 'A');
-        assert_eq!(gsa_data.fix_type, 3);
+        assert_eq!(gsa_data.fix_type, FixType::Fix3D);
         assert_eq!(gsa_data.satellite_ids[0], Some(4));
         assert_eq!(gsa_data.satellite_ids[1], Some(5));
         assert_eq!(gsa_data.satellite_ids[2], None);
@@ -167,12 +224,78 @@ mod tests {
         assert_eq!(gsa_data.pdop, Some(2.5));
         assert_eq!(gsa_data.hdop, Some(1.3));
         assert_eq!(gsa_data.vdop, Some(2.1));
+        assert_eq!(gsa_data.system_id, None);
+    }
+
+    #[test]
+    fn test_gsa_with_system_id() {
+        let parser = NmeaParser::new();
+        let sentence = b"$GNGSA,A,3,80,71,73,79,69,,,,,,,,1.83,1.09,1.47,2*09\r\n";
+
+        let result = parser.parse_sentence_complete(sentence);
+
+        assert!(result.is_some());
+        let msg = result.unwrap();
+        let gsa_data = msg.as_gsa().unwrap();
+        assert_eq!(gsa_data.satellite_ids[0], Some(80));
+        assert_eq!(gsa_data.pdop, Some(1.83));
+        assert_eq!(gsa_data.hdop, Some(1.09));
+        assert_eq!(gsa_data.vdop, Some(1.47));
+        assert_eq!(gsa_data.system_id, Some(SystemId::Glonass));
+    }
+
+    #[test]
+    fn test_gsa_multi_constellation_system_ids() {
+        let parser = NmeaParser::new();
+        let cases = [
+            (
+                b"$GNGSA,A,3,04,05,09,12,24,,,,,,,,1.83,1.09,1.47,1*0C\r\n".as_slice(),
+                SystemId::Gps,
+            ),
+            (
+                b"$GNGSA,A,3,13,15,,,,,,,,,,,1.83,1.09,1.47,3*05\r\n".as_slice(),
+                SystemId::Galileo,
+            ),
+            (
+                b"$GNGSA,A,3,06,14,,,,,,,,,,,1.83,1.09,1.47,4*07\r\n".as_slice(),
+                SystemId::BeiDou,
+            ),
+            (
+                b"$GNGSA,A,3,04,05,09,12,24,,,,,,,,1.83,1.09,1.47,9*04\r\n".as_slice(),
+                SystemId::Unknown,
+            ),
+        ];
+
+        for (sentence, expected) in cases {
+            let msg = parser.parse_sentence_complete(sentence).unwrap();
+            let gsa_data = msg.as_gsa().unwrap();
+            assert_eq!(gsa_data.talker_id, crate::types::TalkerId::GN);
+            assert_eq!(gsa_data.system_id, Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_gsa_unlisted_system_id_round_trip() {
+        let parser = NmeaParser::new();
+        let sentence = b"$GNGSA,A,3,04,05,09,12,24,,,,,,,,1.83,1.09,1.47,9*04\r\n";
+
+        let msg = parser.parse_sentence_complete(sentence).unwrap();
+        let gsa_data = msg.as_gsa().unwrap();
+        assert_eq!(gsa_data.system_id, Some(SystemId::Unknown));
+        assert_eq!(gsa_data.system_id_raw(), Some(9));
+
+        let mut buffer = [0u8; 82];
+        let len = gsa_data.encode(&mut buffer).unwrap();
+        assert!(buffer[..len].ends_with(b",1.83,1.09,1.47,9*04\r\n"));
+
+        let reparsed = parser.parse_sentence_complete(&buffer[..len]).unwrap();
+        assert_eq!(reparsed.as_gsa().unwrap().system_id_raw(), Some(9));
     }
 
     #[test]
     fn test_gsa_manual_mode() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGSA,M,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39\r\n";
+        let sentence = b"$GPGSA,M,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*35\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -188,7 +311,7 @@ mod tests {
     #[test]
     fn test_gsa_2d_fix() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGSA,A,2,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39\r\n";
+        let sentence = b"$GPGSA,A,2,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*38\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -198,13 +321,14 @@ mod tests {
         assert!(gsa.is_some());
 
         let gsa_data = gsa.unwrap();
-        assert_eq!(gsa_data.fix_type, 2);
+        assert_eq!(gsa_data.fix_type, FixType::Fix2D);
+        assert_eq!(gsa_data.fix_type_raw(), 2);
     }
 
     #[test]
     fn test_gsa_no_fix() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGSA,A,1,,,,,,,,,,,,,,,*39\r\n";
+        let sentence = b"$GPGSA,A,1,,,,,,,,,,,,,,,*1E\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -214,14 +338,14 @@ mod tests {
         assert!(gsa.is_some());
 
         let gsa_data = gsa.unwrap();
-        assert_eq!(gsa_data.fix_type, 1);
+        assert_eq!(gsa_data.fix_type, FixType::NoFix);
         assert_eq!(gsa_data.satellite_ids[0], None);
     }
 
     #[test]
     fn test_gsa_partial_satellites() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGSA,A,3,01,,,,,,,,,,,,2.5,1.3,2.1*39\r\n";
+        let sentence = b"$GPGSA,A,3,01,,,,,,,,,,,,2.5,1.3,2.1*35\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -239,7 +363,7 @@ mod tests {
     #[test]
     fn test_gsa_all_satellites() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGSA,A,3,01,02,03,04,05,06,07,08,09,10,11,12,2.5,1.3,2.1*39\r\n";
+        let sentence = b"$GPGSA,A,3,01,02,03,04,05,06,07,08,09,10,11,12,2.5,1.3,2.1*37\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -257,7 +381,7 @@ mod tests {
     #[test]
     fn test_gsa_without_dop() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGSA,A,3,04,05,,09,12,,,24,,,,,,,*39\r\n";
+        let sentence = b"$GPGSA,A,3,04,05,,09,12,,,24,,,,,,,*11\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -275,7 +399,7 @@ mod tests {
     #[test]
     fn test_gsa_missing_mode() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGSA,,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39\r\n";
+        let sentence = b"$GPGSA,,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*78\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -286,7 +410,7 @@ mod tests {
     #[test]
     fn test_gsa_missing_fix_type() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGSA,A,,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39\r\n";
+        let sentence = b"$GPGSA,A,,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*0A\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -316,7 +440,7 @@ mod tests {
     fn test_gsa_different_talker_id() {
         let parser = NmeaParser::new();
         // GNGSA is multi-GNSS
-        let sentence = b"$GNGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39\r\n";
+        let sentence = b"$GNGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*27\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -334,7 +458,7 @@ mod tests {
         let parser = NmeaParser::new();
 
         // Test BeiDou
-        let bd_sentence = b"$BDGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39\r\n";
+        let bd_sentence = b"$BDGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*28\r\n";
         let bd_result = parser.parse_sentence_complete(bd_sentence);
         assert!(bd_result.is_some());
         let bd_msg = bd_result.unwrap();
@@ -342,7 +466,7 @@ mod tests {
         assert_eq!(bd_gsa.talker_id, crate::types::TalkerId::BD);
 
         // Test QZSS
-        let qz_sentence = b"$QZGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39\r\n";
+        let qz_sentence = b"$QZGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*25\r\n";
         let qz_result = parser.parse_sentence_complete(qz_sentence);
         assert!(qz_result.is_some());
         let qz_msg = qz_result.unwrap();
@@ -23,6 +23,7 @@
 //! | 8-11 | Sat 2 Info | - | No | PRN, elevation, azimuth, SNR |
 //! | 12-15 | Sat 3 Info | - | No | PRN, elevation, azimuth, SNR |
 //! | 16-19 | Sat 4 Info | - | No | PRN, elevation, azimuth, SNR |
+//! | last | Signal ID | hex | No | GNSS signal ID (NMEA 4.11+) |
 //!
 //! ## Satellite Information
 //!
@@ -32,6 +33,14 @@
 //! - **Azimuth**: Azimuth in degrees, 0-359° (u16)
 //! - **SNR**: Signal-to-noise ratio in dB, 0-99 (u8)
 //!
+//! ## Signal ID
+//!
+//! NMEA 4.11 receivers append a signal ID after the last satellite block and
+//! emit a separate GSV group for every tracked signal. The meaning of the value
+//! depends on the constellation, e.g. for GPS 1 = L1 C/A and 7 = L5-I, for
+//! Galileo 7 = E1 (L1-BC) and 1 = E5a, for BeiDou 1 = B1I and 5 = B2a.
+//! The raw value is exposed as `signal_id`.
+//!
 //! ## Multi-Sentence Messages
 //!
 //! If more than 4 satellites are visible, multiple GSV messages are sent.
@@ -54,21 +63,27 @@
 //! - Satellite 3: PRN=12, elevation=7°, azimuth=344°, SNR=39dB
 //! - Satellite 4: PRN=14, elevation=22°, azimuth=228°, SNR=45dB
 
+use crate::encode::{EncodeError, SentenceWriter};
 use crate::message::ParsedSentence;
-use crate::types::{MessageType, TalkerId};
+use crate::types::{ErrorDetail, MessageType, TalkerId};
 
 /// GSV - GPS Satellites in view parameters
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct GsvData {
     pub talker_id: TalkerId,
     pub num_messages: u8,
     pub message_num: u8,
     pub satellites_in_view: u8,
     pub satellite_info: [Option<SatelliteInfo>; 4],
+    pub signal_id: Option<u8>,
 }
 
 /// Information about a single satellite
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct SatelliteInfo {
     pub prn: Option<u8>,
     pub elevation: Option<u16>,
@@ -76,7 +91,37 @@ pub struct SatelliteInfo {
     pub snr: Option<u8>,
 }
 
-impl ParsedSentence {
+impl GsvData {
+    /// Encode as a GSV sentence into `buffer`, returning the sentence length
+    ///
+    /// Satellite blocks after the last present one are omitted.
+    pub fn encode(&self, buffer: &mut [u8]) -> Result<usize, EncodeError> {
+        let mut w = SentenceWriter::new(buffer, self.talker_id, "GSV")?;
+        w.field(Some(self.num_messages))?;
+        w.field(Some(self.message_num))?;
+        w.field_padded(Some(self.satellites_in_view), 2)?;
+
+        let blocks = self
+            .satellite_info
+            .iter()
+            .rposition(Option::is_some)
+            .map_or(0, |last| last + 1);
+        for info in &self.satellite_info[..blocks] {
+            let info = info.as_ref();
+            w.field_padded(info.and_then(|s| s.prn), 2)?;
+            w.field_padded(info.and_then(|s| s.elevation), 2)?;
+            w.field_padded(info.and_then(|s| s.azimuth), 3)?;
+            w.field_padded(info.and_then(|s| s.snr), 2)?;
+        }
+
+        if let Some(signal_id) = self.signal_id {
+            w.field_fmt(format_args!("{:X}", signal_id))?;
+        }
+        w.finish()
+    }
+}
+
+impl ParsedSentence<'_> {
     /// Extract GSV message parameters
     ///
     /// Parses the GSV (GPS Satellites in View) message and returns a structured
@@ -84,8 +129,8 @@ impl ParsedSentence {
     ///
     /// # Returns
     ///
-    /// - `Some(GsvData)` if the message is a valid GSV message with all mandatory fields
-    /// - `None` if:
+    /// - `Ok(GsvData)` if the message is a valid GSV message with all mandatory fields
+    /// - `Err(ErrorDetail)` with the failing field and reason if:
     ///   - The message is not a GSV message
     ///   - Any mandatory field is missing or invalid
     ///
@@ -100,6 +145,7 @@ impl ParsedSentence {
     /// - Up to 4 satellite information blocks (fields 4-19)
     /// - Each satellite block contains: PRN, elevation, azimuth, SNR
     /// - Individual fields within a satellite block are also optional
+    /// - Signal ID (NMEA 4.11) following the last satellite block
     ///
     /// # Example
     ///
@@ -118,66 +164,54 @@ impl ParsedSentence {
     ///     }
     /// }
     /// ```
-    pub fn as_gsv(&self) -> Option<GsvData> {
-        if self.message_type != MessageType::GSV {
-            return None;
-        }
+    pub fn as_gsv(&self) -> Result<GsvData, ErrorDetail> {
+        self.expect_type(MessageType::GSV)?;
 
         // Validate mandatory fields
-        let num_messages: u8 = self.parse_field(1)?;
-        let message_num: u8 = self.parse_field(2)?;
-        let satellites_in_view: u8 = self.parse_field(3)?;
-
-        let sat1 = if self.get_field_str(4).is_some() {
-            Some(SatelliteInfo {
-                prn: self.parse_field(4),
-                elevation: self.parse_field(5),
-                azimuth: self.parse_field(6),
-                snr: self.parse_field(7),
-            })
-        } else {
-            None
-        };
-
-        let sat2 = if self.get_field_str(8).is_some() {
-            Some(SatelliteInfo {
-                prn: self.parse_field(8),
-                elevation: self.parse_field(9),
-                azimuth: self.parse_field(10),
-                snr: self.parse_field(11),
-            })
+        let num_messages: u8 = self.required_field(1)?;
+        let message_num: u8 = self.required_field(2)?;
+        let satellites_in_view: u8 = self.required_field(3)?;
+
+        // NMEA 4.11 appends a signal ID after the satellite blocks, so a
+        // sentence carrying one has 4 + 4*n + 1 fields
+        let has_signal_id = self.field_count > 4 && (self.field_count - 4) % 4 == 1;
+        let signal_id = if has_signal_id {
+            self.parse_field_hex(self.field_count - 1)
         } else {
             None
         };
-
-        let sat3 = if self.get_field_str(12).is_some() {
-            Some(SatelliteInfo {
-                prn: self.parse_field(12),
-                elevation: self.parse_field(13),
-                azimuth: self.parse_field(14),
-                snr: self.parse_field(15),
-            })
+        let blocks_end = if has_signal_id {
+            self.field_count - 1
         } else {
-            None
+            self.field_count
         };
 
-        let sat4 = if self.get_field_str(16).is_some() {
-            Some(SatelliteInfo {
-                prn: self.parse_field(16),
-                elevation: self.parse_field(17),
-                azimuth: self.parse_field(18),
-                snr: self.parse_field(19),
-            })
-        } else {
-            None
-        };
-
-        Some(GsvData {
+        Ok(GsvData {
             talker_id: self.talker_id,
             num_messages,
             message_num,
             satellites_in_view,
-            satellite_info: [sat1, sat2, sat3, sat4],
+            satellite_info: [
+                self.satellite_block(4, blocks_end),
+                self.satellite_block(8, blocks_end),
+                self.satellite_block(12, blocks_end),
+                self.satellite_block(16, blocks_end),
+            ],
+            signal_id,
+        })
+    }
+
+    /// Parse the satellite block starting at `index`, ignoring fields at or past `end`
+    fn satellite_block(&self, index: usize, end: usize) -> Option<SatelliteInfo> {
+        if index >= end || self.get_field_str(index).is_none() {
+            return None;
+        }
+
+        Some(SatelliteInfo {
+            prn: self.parse_field(index),
+            elevation: self.parse_field(index + 1),
+            azimuth: self.parse_field(index + 2),
+            snr: self.parse_field(index + 3),
         })
     }
 }
@@ -218,12 +252,76 @@ mod tests {
         assert_eq!(sat2.elevation, Some(17));
         assert_eq!(sat2.azimuth, Some(308));
         assert_eq!(sat2.snr, Some(41));
+        assert_eq!(gsv_data.signal_id, None);
+    }
+
+    #[test]
+    fn test_gsv_with_signal_id() {
+        let parser = NmeaParser::new();
+        let sentence =
+            b"$GPGSV,3,1,12,02,17,315,44,04,77,268,47,05,55,147,45,07,32,195,42,1*66\r\n";
+
+        let result = parser.parse_sentence_complete(sentence);
+
+        assert!(result.is_some());
+        let msg = result.unwrap();
+        let gsv_data = msg.as_gsv().unwrap();
+        assert_eq!(gsv_data.satellites_in_view, 12);
+        assert_eq!(gsv_data.signal_id, Some(1));
+
+        let sat4 = gsv_data.satellite_info[3].as_ref().unwrap();
+        assert_eq!(sat4.prn, Some(7));
+        assert_eq!(sat4.snr, Some(42));
+    }
+
+    #[test]
+    fn test_gsv_signal_id_with_partial_blocks() {
+        let parser = NmeaParser::new();
+        let sentence = b"$GPGSV,1,1,02,05,55,147,38,07,32,195,35,8*6E\r\n";
+
+        let result = parser.parse_sentence_complete(sentence);
+
+        assert!(result.is_some());
+        let msg = result.unwrap();
+        let gsv_data = msg.as_gsv().unwrap();
+        assert_eq!(gsv_data.signal_id, Some(8));
+        assert!(gsv_data.satellite_info[1].is_some());
+        assert!(gsv_data.satellite_info[2].is_none());
+    }
+
+    #[test]
+    fn test_gsv_signal_id_without_satellites() {
+        let parser = NmeaParser::new();
+        let sentence = b"$GAGSV,1,1,00,7*73\r\n";
+
+        let result = parser.parse_sentence_complete(sentence);
+
+        assert!(result.is_some());
+        let msg = result.unwrap();
+        let gsv_data = msg.as_gsv().unwrap();
+        assert_eq!(gsv_data.satellites_in_view, 0);
+        assert_eq!(gsv_data.signal_id, Some(7));
+        // The signal ID must not be mistaken for a satellite PRN
+        assert!(gsv_data.satellite_info[0].is_none());
+    }
+
+    #[test]
+    fn test_gsv_hex_signal_id() {
+        let parser = NmeaParser::new();
+        let sentence = b"$GBGSV,1,1,01,19,65,310,40,B*39\r\n";
+
+        let result = parser.parse_sentence_complete(sentence);
+
+        assert!(result.is_some());
+        let msg = result.unwrap();
+        let gsv_data = msg.as_gsv().unwrap();
+        assert_eq!(gsv_data.signal_id, Some(0xB));
     }
 
     #[test]
     fn test_gsv_partial_satellites() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGSV,1,1,02,01,40,083,46,02,17,308,*75\r\n";
+        let sentence = b"$GPGSV,1,1,02,01,40,083,46,02,17,308,*78\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -254,7 +352,7 @@ mod tests {
     #[test]
     fn test_gsv_single_satellite() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGSV,1,1,01,01,40,083,46*75\r\n";
+        let sentence = b"$GPGSV,1,1,01,01,40,083,46*44\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -272,7 +370,7 @@ mod tests {
     #[test]
     fn test_gsv_missing_num_messages() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGSV,,1,08,01,40,083,46,02,17,308,41,12,07,344,39,14,22,228,45*75\r\n";
+        let sentence = b"$GPGSV,,1,08,01,40,083,46,02,17,308,41,12,07,344,39,14,22,228,45*47\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -283,7 +381,7 @@ mod tests {
     #[test]
     fn test_gsv_missing_message_num() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGSV,2,,08,01,40,083,46,02,17,308,41,12,07,344,39,14,22,228,45*75\r\n";
+        let sentence = b"$GPGSV,2,,08,01,40,083,46,02,17,308,41,12,07,344,39,14,22,228,45*44\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -294,7 +392,7 @@ mod tests {
     #[test]
     fn test_gsv_missing_satellites_in_view() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGSV,2,1,,01,40,083,46,02,17,308,41,12,07,344,39,14,22,228,45*75\r\n";
+        let sentence = b"$GPGSV,2,1,,01,40,083,46,02,17,308,41,12,07,344,39,14,22,228,45*7D\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -306,7 +404,7 @@ mod tests {
     fn test_gsv_satellite_partial_info() {
         let parser = NmeaParser::new();
         // Satellite with PRN but missing other fields
-        let sentence = b"$GPGSV,1,1,01,01,,,*75\r\n";
+        let sentence = b"$GPGSV,1,1,01,01,,,*79\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -327,7 +425,7 @@ mod tests {
     #[test]
     fn test_gsv_zero_elevation_azimuth() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGSV,1,1,01,01,0,0,46*75\r\n";
+        let sentence = b"$GPGSV,1,1,01,01,0,0,46*7B\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -345,7 +443,7 @@ mod tests {
     #[test]
     fn test_gsv_max_elevation() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGSV,1,1,01,01,90,180,46*75\r\n";
+        let sentence = b"$GPGSV,1,1,01,01,90,180,46*4B\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -362,7 +460,7 @@ mod tests {
     #[test]
     fn test_gsv_max_azimuth() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGSV,1,1,01,01,45,359,46*75\r\n";
+        let sentence = b"$GPGSV,1,1,01,01,45,359,46*45\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -380,7 +478,7 @@ mod tests {
     fn test_gsv_different_talker_id() {
         let parser = NmeaParser::new();
         // GNGSV is multi-GNSS
-        let sentence = b"$GNGSV,2,1,08,01,40,083,46,02,17,308,41,12,07,344,39,14,22,228,45*75\r\n";
+        let sentence = b"$GNGSV,2,1,08,01,40,083,46,02,17,308,41,12,07,344,39,14,22,228,45*6B\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -409,7 +507,7 @@ mod tests {
 
         // Test GLONASS satellites
         let gl_sentence =
-            b"$GLGSV,2,1,08,65,40,083,46,66,17,308,41,75,07,344,39,76,22,228,45*75\r\n";
+            b"$GLGSV,2,1,08,65,40,083,46,66,17,308,41,75,07,344,39,76,22,228,45*6C\r\n";
         let gl_result = parser.parse_sentence_complete(gl_sentence);
         assert!(gl_result.is_some());
         let gl_msg = gl_result.unwrap();
@@ -418,7 +516,7 @@ mod tests {
 
         // Test Galileo satellites
         let ga_sentence =
-            b"$GAGSV,2,1,08,01,40,083,46,02,17,308,41,12,07,344,39,14,22,228,45*75\r\n";
+            b"$GAGSV,2,1,08,01,40,083,46,02,17,308,41,12,07,344,39,14,22,228,45*64\r\n";
         let ga_result = parser.parse_sentence_complete(ga_sentence);
         assert!(ga_result.is_some());
         let ga_msg = ga_result.unwrap();
@@ -440,7 +538,7 @@ mod tests {
         assert_eq!(gsv1_data.num_messages, 2);
 
         // Second message of sequence
-        let sentence2 = b"$GPGSV,2,2,08,20,35,073,44,21,25,210,42,25,15,120,40,32,10,045,38*75\r\n";
+        let sentence2 = b"$GPGSV,2,2,08,20,35,073,44,21,25,210,42,25,15,120,40,32,10,045,38*7E\r\n";
         let result2 = parser.parse_sentence_complete(sentence2);
         assert!(result2.is_some());
         let msg2 = result2.unwrap();
@@ -0,0 +1,158 @@
+//! Proprietary sentence implementation
+//!
+//! Proprietary sentences start with `$P` followed by a three-character vendor
+//! mnemonic (e.g. `GRM` for Garmin, `UBX` for u-blox, `STM` for
+//! STMicroelectronics) and a vendor-defined sentence identifier. Their content
+//! is not standardized, so [`ProprietaryData`] exposes the vendor, the
+//! sentence identifier and the raw fields.
+//!
+//! ## Message Format
+//!
+//! ```text
+//! $Pvvvsss,...*hh
+//! ```
+//!
+//! ## Example
+//!
+//! ```text
+//! $PGRME,15.0,M,45.0,M,25.0,M*1C
+//! ```
+//!
+//! This represents:
+//! - Vendor: GRM (Garmin)
+//! - Sentence identifier: E (estimated error)
+//! - Fields: `15.0`, `M`, `45.0`, `M`, `25.0`, `M`
+
+use crate::encode::EncodeError;
+use crate::message::GenericSentence;
+use crate::types::TalkerId;
+
+/// Proprietary sentence with vendor mnemonic and raw fields
+///
+/// Like `GenericSentence`, the sentence is kept in a fixed buffer of 80 bytes;
+/// longer proprietary sentences are only available through
+/// `NmeaParser::parse_bytes_ref`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ProprietaryData {
+    sentence: GenericSentence,
+}
+
+impl ProprietaryData {
+    /// Create proprietary data from a complete sentence starting with `$P`
+    ///
+    /// Returns `None` if the address is shorter than `P` plus the vendor
+    /// mnemonic or the sentence does not fit a `GenericSentence`.
+    pub(crate) fn from_sentence(sentence: &[u8], talker_id: TalkerId) -> Option<Self> {
+        let sentence = GenericSentence::from_sentence(sentence, talker_id)?;
+        if sentence.address().len() < 4 {
+            return None;
+        }
+        Some(ProprietaryData { sentence })
+    }
+
+    /// Get the vendor mnemonic as string slice (e.g. "GRM")
+    pub fn vendor(&self) -> &str {
+        self.sentence.address().get(1..4).unwrap_or("")
+    }
+
+    /// Get the vendor-defined sentence identifier as string slice (e.g. "E" for `$PGRME`)
+    ///
+    /// May be empty when the vendor carries the identifier in the first field
+    /// (e.g. `$PUBX,00,...`).
+    pub fn sentence_id(&self) -> &str {
+        self.sentence.address().get(4..).unwrap_or("")
+    }
+
+    /// Get the address field as string slice (e.g. "PGRME")
+    pub fn address(&self) -> &str {
+        self.sentence.address()
+    }
+
+    /// Number of data fields following the address field
+    pub fn field_count(&self) -> usize {
+        self.sentence.field_count()
+    }
+
+    /// Get a data field as string slice (index 0 is the first field after the address)
+    ///
+    /// Returns `None` if the field is empty, missing or not valid UTF-8.
+    pub fn field(&self, index: usize) -> Option<&str> {
+        self.sentence.field(index)
+    }
+
+    /// Iterate over the data fields following the address field
+    pub fn fields(&self) -> impl Iterator<Item = &str> {
+        self.sentence.fields()
+    }
+
+    /// Get the raw sentence bytes (from `$` to the checksum, without line ending)
+    pub fn as_bytes(&self) -> &[u8] {
+        self.sentence.as_bytes()
+    }
+
+    /// Get the sentence as a `GenericSentence`, e.g. to pass it to a custom decoder
+    pub fn as_generic(&self) -> &GenericSentence {
+        &self.sentence
+    }
+
+    /// Encode the sentence into `buffer` with a recomputed checksum,
+    /// returning the sentence length
+    pub fn encode(&self, buffer: &mut [u8]) -> Result<usize, EncodeError> {
+        self.sentence.encode(buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::MessageType;
+    use crate::NmeaParser;
+
+    #[test]
+    fn test_proprietary_garmin() {
+        let parser = NmeaParser::new();
+        let sentence = b"$PGRME,15.0,M,45.0,M,25.0,M*1C\r\n";
+
+        let msg = parser.parse_sentence_complete(sentence).unwrap();
+        assert_eq!(msg.message_type(), MessageType::Proprietary);
+
+        let data = msg.as_proprietary().unwrap();
+        assert_eq!(data.vendor(), "GRM");
+        assert_eq!(data.sentence_id(), "E");
+        assert_eq!(data.address(), "PGRME");
+        assert_eq!(data.field_count(), 6);
+        assert_eq!(data.field(0), Some("15.0"));
+        assert_eq!(data.field(5), Some("M"));
+    }
+
+    #[test]
+    fn test_proprietary_ublox_identifier_in_field() {
+        let parser = NmeaParser::new();
+        let sentence = b"$PUBX,00,081350.00,4717.113210,N*5B\r\n";
+
+        let msg = parser.parse_sentence_complete(sentence).unwrap();
+        let data = msg.as_proprietary().unwrap();
+        assert_eq!(data.vendor(), "UBX");
+        assert_eq!(data.sentence_id(), "");
+        assert_eq!(data.field(0), Some("00"));
+    }
+
+    #[test]
+    fn test_proprietary_ending_with_q_is_not_query() {
+        let parser = NmeaParser::new();
+        let sentence = b"$PSTMQ,1*56\r\n";
+
+        let msg = parser.parse_sentence_complete(sentence).unwrap();
+        assert_eq!(msg.message_type(), MessageType::Proprietary);
+        assert_eq!(msg.as_proprietary().unwrap().vendor(), "STM");
+    }
+
+    #[test]
+    fn test_proprietary_too_short_address() {
+        let parser = NmeaParser::new();
+        let sentence = b"$PGR,1,2,3*59\r\n";
+
+        assert!(parser.parse_sentence_complete(sentence).is_none());
+    }
+}
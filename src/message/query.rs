@@ -0,0 +1,202 @@
+//! Query sentence implementation
+//!
+//! A query sentence is sent by a listener that wants a talker to transmit a
+//! specific sentence. Instead of a three-character sentence formatter, the
+//! address field holds the requester talker ID, the addressed listener ID and
+//! the `Q` query character.
+//!
+//! ## Message Format
+//!
+//! ```text
+//! $ttllQ,sss*hh
+//! ```
+//!
+//! ## Fields
+//!
+//! | Index | Field | Type | Mandatory | Description |
+//! |-------|-------|------|-----------|-------------|
+//! | 0 | Address | String | Yes | Requester (tt), listener (ll) and `Q` |
+//! | 1 | Sentence | String | Yes | Three-character formatter of the requested sentence |
+//!
+//! ## Example
+//!
+//! ```text
+//! $CCGPQ,GGA*2B
+//! ```
+//!
+//! This represents:
+//! - Requester: CC (computer)
+//! - Addressed listener: GP (GPS receiver)
+//! - Requested sentence: GGA
+
+use crate::encode::{EncodeError, SentenceWriter};
+use crate::fixed_str::FixedStr;
+use crate::message::ParsedSentence;
+use crate::types::{ErrorDetail, ErrorReason, MessageType, TalkerId};
+
+/// Query - request for a specific sentence from a talker
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct QueryData {
+    pub talker_id: TalkerId,
+    pub listener_id: TalkerId,
+    sentence: FixedStr<3>,
+}
+
+impl QueryData {
+    /// Get the requested sentence formatter as string slice (e.g. "GGA")
+    pub fn requested_sentence(&self) -> &str {
+        self.sentence.as_str()
+    }
+
+    /// Get the requested sentence as a message type
+    ///
+    /// Returns `MessageType::Unknown` if the requested sentence is not supported
+    /// by this library.
+    pub fn requested_message_type(&self) -> MessageType {
+        MessageType::from_formatter(self.sentence.as_bytes())
+    }
+
+    /// Encode as a query sentence into `buffer`, returning the sentence length
+    pub fn encode(&self, buffer: &mut [u8]) -> Result<usize, EncodeError> {
+        let mut w = SentenceWriter::new(buffer, self.talker_id, self.listener_id.as_str())?;
+        w.push(b"Q")?;
+        w.field(Some(self.requested_sentence()))?;
+        w.finish()
+    }
+}
+
+impl ParsedSentence<'_> {
+    /// Extract query sentence parameters
+    ///
+    /// Parses a query sentence and returns a structured `QueryData` object
+    /// with the requester, the addressed listener and the requested sentence.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(QueryData)` if the message is a valid query sentence
+    /// - `Err(ErrorDetail)` with the failing field and reason if:
+    ///   - The message is not a query sentence
+    ///   - The requested sentence formatter is missing or not 3 characters long
+    ///
+    /// # Mandatory Fields
+    ///
+    /// - Address (field 0) - requester, listener and `Q`
+    /// - Requested sentence (field 1)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustedbytes_nmea::{NmeaParser, MessageType, TalkerId};
+    ///
+    /// let parser = NmeaParser::new();
+    /// let sentence = b"$CCGPQ,GGA*2B\r\n";
+    ///
+    /// let result = parser.parse_bytes(sentence);
+    /// if let Ok((Some(msg), _consumed)) = result {
+    ///     if let Some(query) = msg.as_query() {
+    ///         assert_eq!(query.listener_id, TalkerId::GP);
+    ///         assert_eq!(query.requested_sentence(), "GGA");
+    ///         assert_eq!(query.requested_message_type(), MessageType::GGA);
+    ///     }
+    /// }
+    /// ```
+    pub fn as_query(&self) -> Result<QueryData, ErrorDetail> {
+        self.expect_type(MessageType::Query)?;
+
+        // Validate mandatory fields
+        let address = self.required_str(0)?.as_bytes();
+        if address.len() != 5 {
+            return Err(self.field_error(ErrorReason::InvalidValue, 0));
+        }
+        let sentence = self.required_str(1)?.as_bytes();
+        if sentence.len() != 3 {
+            return Err(self.field_error(ErrorReason::InvalidValue, 1));
+        }
+
+        Ok(QueryData {
+            talker_id: self.talker_id,
+            listener_id: TalkerId::from_bytes(&address[2..4]),
+            sentence: FixedStr::new(sentence),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{MessageType, TalkerId};
+    use crate::NmeaParser;
+
+    #[test]
+    fn test_query_gga_request() {
+        let parser = NmeaParser::new();
+        let sentence = b"$CCGPQ,GGA*2B\r\n";
+
+        let result = parser.parse_sentence_complete(sentence);
+
+        assert!(result.is_some());
+        let msg = result.unwrap();
+        assert_eq!(msg.message_type(), MessageType::Query);
+        let query = msg.as_query();
+        assert!(query.is_some());
+
+        let query_data = query.unwrap();
+        assert_eq!(query_data.talker_id, TalkerId::CC);
+        assert_eq!(query_data.listener_id, TalkerId::GP);
+        assert_eq!(query_data.requested_sentence(), "GGA");
+        assert_eq!(query_data.requested_message_type(), MessageType::GGA);
+    }
+
+    #[test]
+    fn test_query_between_known_talkers() {
+        let parser = NmeaParser::new();
+        let sentence = b"$GPECQ,RMC*30\r\n";
+
+        let result = parser.parse_sentence_complete(sentence);
+
+        assert!(result.is_some());
+        let msg = result.unwrap();
+        let query_data = msg.as_query().unwrap();
+        assert_eq!(msg.talker_id(), TalkerId::GP);
+        assert_eq!(query_data.talker_id, TalkerId::GP);
+        assert_eq!(query_data.listener_id, TalkerId::EC);
+        assert_eq!(query_data.requested_message_type(), MessageType::RMC);
+    }
+
+    #[test]
+    fn test_query_unsupported_sentence() {
+        let parser = NmeaParser::new();
+        let sentence = b"$CCGPQ,XYZ*31\r\n";
+
+        let result = parser.parse_sentence_complete(sentence);
+
+        assert!(result.is_some());
+        let msg = result.unwrap();
+        let query_data = msg.as_query().unwrap();
+        assert_eq!(query_data.requested_sentence(), "XYZ");
+        assert_eq!(query_data.requested_message_type(), MessageType::Unknown);
+    }
+
+    #[test]
+    fn test_query_missing_sentence() {
+        let parser = NmeaParser::new();
+        let sentence = b"$CCGPQ,*6A\r\n";
+
+        let result = parser.parse_sentence_complete(sentence);
+
+        // Should return None because the requested sentence is mandatory
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_query_wrong_message_type_extraction() {
+        let parser = NmeaParser::new();
+        let sentence = b"$CCGPQ,GGA*2B\r\n";
+
+        let msg = parser.parse_sentence_complete(sentence).unwrap();
+
+        assert!(msg.as_gga().is_none());
+        assert!(msg.as_query().is_some());
+    }
+}
@@ -8,6 +8,7 @@
 //!
 //! ```text
 //! $GPRMC,hhmmss.ss,A,llll.ll,a,yyyyy.yy,a,x.x,x.x,ddmmyy,x.x,a*hh
+//! $GNRMC,hhmmss.ss,A,llll.ll,a,yyyyy.yy,a,x.x,x.x,ddmmyy,x.x,a,m,s*hh  (NMEA 4.1+)
 //! ```
 //!
 //! ## Fields
@@ -16,16 +17,38 @@
 //! |-------|-------|------|-----------|-------------|
 //! | 0 | Sentence ID | String | Yes | Message type (GPRMC, GNRMC, etc.) |
 //! | 1 | UTC Time | String | Yes | hhmmss.ss format |
-//! | 2 | Status | char | Yes | A = Valid, V = Invalid |
-//! | 3 | Latitude | f64 | Yes | ddmm.mmmmm format |
+//! | 2 | Status | Status | Yes | A = Valid, V = Invalid |
+//! | 3 | Latitude | Coordinate | Yes | ddmm.mmmmm format |
 //! | 4 | N/S Indicator | char | Yes | N = North, S = South |
-//! | 5 | Longitude | f64 | Yes | dddmm.mmmmm format |
+//! | 5 | Longitude | Coordinate | Yes | dddmm.mmmmm format |
 //! | 6 | E/W Indicator | char | Yes | E = East, W = West |
 //! | 7 | Speed (knots) | f32 | Yes | Speed over ground in knots |
 //! | 8 | Track Angle | f32 | Yes | Track angle in degrees |
 //! | 9 | Date | String | Yes | ddmmyy format |
 //! | 10 | Mag Variation | f32 | No | Magnetic variation in degrees |
 //! | 11 | Mag Var Dir | char | No | E = East, W = West |
+//! | 12 | Mode Indicator | char | No | Positioning mode (NMEA 2.3+) |
+//! | 13 | Nav Status | char | No | Navigational status (NMEA 4.1+) |
+//!
+//! ## Mode Indicator and Navigational Status
+//!
+//! Sentences from NMEA 2.3 onwards append a positioning mode indicator:
+//! - N = No fix
+//! - A = Autonomous GNSS fix
+//! - D = Differential mode
+//! - R = RTK fixed
+//! - F = RTK float
+//! - E = Estimated (dead reckoning) mode
+//! - M = Manual input mode
+//! - S = Simulator mode
+//!
+//! NMEA 4.1 adds a navigational status indicator:
+//! - S = Safe
+//! - C = Caution
+//! - U = Unsafe
+//! - V = Not valid for navigation
+//!
+//! Both fields are optional, so older 10/11-field sentences are still accepted.
 //!
 //! ## Example
 //!
@@ -42,41 +65,258 @@
 //! - Date: March 23, 1994
 //! - Magnetic variation: 3.1° West
 
+use crate::coordinate::{self, Coordinate, Degrees};
+use crate::datetime::{NmeaDate, NmeaTime};
+use crate::encode::{EncodeError, SentenceWriter};
 use crate::message::ParsedSentence;
-use crate::types::{MessageType, TalkerId};
+use crate::types::{ErrorDetail, MessageType, Status, TalkerId};
 
 /// RMC - Recommended Minimum Navigation Information parameters
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct RmcData {
     pub talker_id: TalkerId,
-    time_data: [u8; 16],
-    time_len: u8,
-    pub status: char,
-    pub latitude: f64,
+    time: NmeaTime,
+    pub status: Status,
+    status_raw: char,
+    pub latitude: Coordinate,
     pub lat_direction: char,
-    pub longitude: f64,
+    pub longitude: Coordinate,
     pub lon_direction: char,
     pub speed_knots: f32,
     pub track_angle: f32,
-    date_data: [u8; 8],
-    date_len: u8,
+    date: NmeaDate,
     pub magnetic_variation: Option<f32>,
     pub mag_var_direction: Option<char>,
+    pub mode_indicator: Option<char>,
+    pub nav_status: Option<char>,
 }
 
 impl RmcData {
-    /// Get time as string slice
-    pub fn time(&self) -> &str {
-        core::str::from_utf8(&self.time_data[..self.time_len as usize]).unwrap_or("")
+    /// Create an `RmcBuilder` generating RMC sentences
+    pub fn builder() -> RmcBuilder {
+        RmcBuilder::new()
+    }
+
+    /// Get the latitude in signed decimal degrees (negative in the southern hemisphere)
+    ///
+    /// Returns `None` if the direction is neither `N` nor `S`.
+    pub fn latitude_degrees(&self) -> Option<Degrees> {
+        coordinate::latitude_degrees(self.latitude, self.lat_direction)
+    }
+
+    /// Get the longitude in signed decimal degrees (negative in the western hemisphere)
+    ///
+    /// Returns `None` if the direction is neither `E` nor `W`.
+    pub fn longitude_degrees(&self) -> Option<Degrees> {
+        coordinate::longitude_degrees(self.longitude, self.lon_direction)
+    }
+
+    /// Get the latitude in signed units of 1e-7 degrees (`COORDINATE_SCALE`
+    /// units per degree)
+    ///
+    /// Returns `None` if the direction is neither `N` nor `S`.
+    #[cfg(feature = "fixed-point")]
+    pub fn latitude_fixed(&self) -> Option<i32> {
+        self.latitude_degrees().map(coordinate::to_fixed)
+    }
+
+    /// Get the longitude in signed units of 1e-7 degrees (`COORDINATE_SCALE`
+    /// units per degree)
+    ///
+    /// Returns `None` if the direction is neither `E` nor `W`.
+    #[cfg(feature = "fixed-point")]
+    pub fn longitude_fixed(&self) -> Option<i32> {
+        self.longitude_degrees().map(coordinate::to_fixed)
+    }
+
+    /// Get the UTC time
+    pub fn time(&self) -> NmeaTime {
+        self.time
+    }
+
+    /// Get the raw status character as transmitted
+    pub fn status_raw(&self) -> char {
+        self.status_raw
+    }
+
+    /// Get the date
+    pub fn date(&self) -> NmeaDate {
+        self.date
+    }
+
+    /// Encode as an RMC sentence into `buffer`, returning the sentence length
+    ///
+    /// `status` is written from its typed value; the raw value is kept for
+    /// `Status::Unknown`. The mode indicator and navigational status are only
+    /// written when present.
+    pub fn encode(&self, buffer: &mut [u8]) -> Result<usize, EncodeError> {
+        let mut w = SentenceWriter::new(buffer, self.talker_id, "RMC")?;
+        w.time(self.time)?;
+        w.field(Some(self.status.to_char().unwrap_or(self.status_raw)))?;
+        w.coordinate(self.latitude, 2)?;
+        w.field(Some(self.lat_direction))?;
+        w.coordinate(self.longitude, 3)?;
+        w.field(Some(self.lon_direction))?;
+        w.field(Some(self.speed_knots))?;
+        w.field(Some(self.track_angle))?;
+        w.date(self.date)?;
+        w.field(self.magnetic_variation)?;
+        w.field(self.mag_var_direction)?;
+        if self.mode_indicator.is_some() || self.nav_status.is_some() {
+            w.field(self.mode_indicator)?;
+        }
+        if self.nav_status.is_some() {
+            w.field(self.nav_status)?;
+        }
+        w.finish()
+    }
+}
+
+/// Builder generating RMC sentences from typed values, e.g. for simulation
+///
+/// Unset fields default to midnight UTC, date `000000`, status void,
+/// position 0°N 0°E, zero speed and track, and empty optional fields.
+///
+/// # Example
+///
+/// ```
+/// use rustedbytes_nmea::{NmeaDate, NmeaTime, RmcBuilder, Status};
+///
+/// let (lat, lon) = (48.1173, 11.516_666_67);
+/// let rmc = RmcBuilder::new()
+///     .time(NmeaTime { hour: 12, minute: 35, second: 19, millis: 0 })
+///     .date(NmeaDate { year: 1994, month: 3, day: 23 })
+///     .status(Status::Valid)
+///     .position(lat, lon)
+///     .speed_knots(22.4)
+///     .track_angle(84.4)
+///     .magnetic_variation(-3.1);
+///
+/// let mut buffer = [0u8; 82];
+/// let len = rmc.encode(&mut buffer).unwrap();
+/// assert_eq!(
+///     &buffer[..len],
+///     b"$GPRMC,123519,A,4807.03800,N,01131.00000,E,22.4,84.4,230394,3.1,W*6A\r\n"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct RmcBuilder {
+    data: RmcData,
+}
+
+impl RmcBuilder {
+    /// Create a builder for a `$GPRMC` sentence with default values
+    pub fn new() -> Self {
+        RmcBuilder {
+            data: RmcData {
+                talker_id: TalkerId::GP,
+                time: NmeaTime::default(),
+                status: Status::Invalid,
+                status_raw: 'V',
+                latitude: Coordinate::default(),
+                lat_direction: 'N',
+                longitude: Coordinate::default(),
+                lon_direction: 'E',
+                speed_knots: 0.0,
+                track_angle: 0.0,
+                date: NmeaDate {
+                    year: 2000,
+                    month: 0,
+                    day: 0,
+                },
+                magnetic_variation: None,
+                mag_var_direction: None,
+                mode_indicator: None,
+                nav_status: None,
+            },
+        }
+    }
+
+    /// Set the talker ID (default `GP`)
+    pub fn talker_id(mut self, talker_id: TalkerId) -> Self {
+        self.data.talker_id = talker_id;
+        self
+    }
+
+    /// Set the UTC time of the fix
+    pub fn time(mut self, time: NmeaTime) -> Self {
+        self.data.time = time;
+        self
+    }
+
+    /// Set the UTC date of the fix (only the last two digits of the year are sent)
+    pub fn date(mut self, date: NmeaDate) -> Self {
+        self.data.date = date;
+        self
+    }
+
+    /// Set the data status (`Status::Unknown` is written as void)
+    pub fn status(mut self, status: Status) -> Self {
+        self.data.status_raw = status.to_char().unwrap_or('V');
+        self.data.status = Status::from_char(self.data.status_raw);
+        self
+    }
+
+    /// Set the position in signed decimal degrees (negative south and west)
+    pub fn position(mut self, latitude: Degrees, longitude: Degrees) -> Self {
+        (self.data.latitude, self.data.lat_direction) =
+            coordinate::from_degrees(latitude, 'N', 'S');
+        (self.data.longitude, self.data.lon_direction) =
+            coordinate::from_degrees(longitude, 'E', 'W');
+        self
+    }
+
+    /// Set the speed over ground in knots
+    pub fn speed_knots(mut self, knots: f32) -> Self {
+        self.data.speed_knots = knots;
+        self
+    }
+
+    /// Set the track angle in degrees true
+    pub fn track_angle(mut self, degrees: f32) -> Self {
+        self.data.track_angle = degrees;
+        self
+    }
+
+    /// Set the magnetic variation in degrees (negative west)
+    pub fn magnetic_variation(mut self, degrees: f32) -> Self {
+        self.data.magnetic_variation = Some(degrees.abs());
+        self.data.mag_var_direction = Some(if degrees < 0.0 { 'W' } else { 'E' });
+        self
+    }
+
+    /// Set the mode indicator (NMEA 2.3+, e.g. `A` autonomous, `D` differential)
+    pub fn mode_indicator(mut self, mode: char) -> Self {
+        self.data.mode_indicator = Some(mode);
+        self
+    }
+
+    /// Set the navigational status (NMEA 4.1+, e.g. `V` for no warning)
+    pub fn nav_status(mut self, nav_status: char) -> Self {
+        self.data.nav_status = Some(nav_status);
+        self
     }
 
-    /// Get date as string slice
-    pub fn date(&self) -> &str {
-        core::str::from_utf8(&self.date_data[..self.date_len as usize]).unwrap_or("")
+    /// Get the RMC data described by the builder
+    pub fn build(self) -> RmcData {
+        self.data
+    }
+
+    /// Encode the sentence into `buffer`, returning the sentence length
+    pub fn encode(&self, buffer: &mut [u8]) -> Result<usize, EncodeError> {
+        self.data.encode(buffer)
     }
 }
 
-impl ParsedSentence {
+impl Default for RmcBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParsedSentence<'_> {
     /// Extract RMC message parameters
     ///
     /// Parses the RMC (Recommended Minimum Navigation Information) message and
@@ -84,8 +324,8 @@ impl ParsedSentence {
     ///
     /// # Returns
     ///
-    /// - `Some(RmcData)` if the message is a valid RMC message with all mandatory fields
-    /// - `None` if:
+    /// - `Ok(RmcData)` if the message is a valid RMC message with all mandatory fields
+    /// - `Err(ErrorDetail)` with the failing field and reason if:
     ///   - The message is not an RMC message
     ///   - Any mandatory field is missing or invalid
     ///
@@ -104,11 +344,12 @@ impl ParsedSentence {
     /// # Optional Fields
     ///
     /// - Magnetic variation and direction are optional
+    /// - Mode indicator (field 12) and navigational status (field 13) are optional
     ///
     /// # Example
     ///
     /// ```
-    /// use rustedbytes_nmea::{NmeaParser, MessageType};
+    /// use rustedbytes_nmea::{NmeaParser, MessageType, Status};
     ///
     /// let parser = NmeaParser::new();
     /// let sentence = b"$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A\r\n";
@@ -116,61 +357,51 @@ impl ParsedSentence {
     /// let result = parser.parse_bytes(sentence);
     /// if let Ok((Some(msg), _consumed)) = result {
     ///     if let Some(rmc) = msg.as_rmc() {
-    ///         assert_eq!(rmc.time(), "123519");
-    ///         assert_eq!(rmc.status, 'A');
+    ///         assert_eq!(rmc.time().hour, 12);
+    ///         assert_eq!(rmc.status, Status::Valid);
     ///         assert_eq!(rmc.speed_knots, 22.4);
     ///     }
     /// }
     /// ```
-    pub fn as_rmc(&self) -> Option<RmcData> {
-        if self.message_type != MessageType::RMC {
-            return None;
-        }
+    pub fn as_rmc(&self) -> Result<RmcData, ErrorDetail> {
+        self.expect_type(MessageType::RMC)?;
 
         // Validate mandatory fields
-        let time_str = self.get_field_str(1)?;
-        let status = self.parse_field_char(2)?;
-        let latitude: f64 = self.parse_field(3)?;
-        let lat_direction = self.parse_field_char(4)?;
-        let longitude: f64 = self.parse_field(5)?;
-        let lon_direction = self.parse_field_char(6)?;
-        let speed_knots: f32 = self.parse_field(7)?;
-        let track_angle: f32 = self.parse_field(8)?;
-        let date_str = self.get_field_str(9)?;
-
-        // Copy time to fixed array
-        let mut time_data = [0u8; 16];
-        let time_bytes = time_str.as_bytes();
-        let time_len = time_bytes.len().min(16) as u8;
-        time_data[..time_len as usize].copy_from_slice(&time_bytes[..time_len as usize]);
-
-        // Copy date to fixed array
-        let mut date_data = [0u8; 8];
-        let date_bytes = date_str.as_bytes();
-        let date_len = date_bytes.len().min(8) as u8;
-        date_data[..date_len as usize].copy_from_slice(&date_bytes[..date_len as usize]);
-
-        Some(RmcData {
+        let time = self.required_time(1)?;
+        let status = self.required_char(2)?;
+        let latitude = self.required_coordinate(3)?;
+        let lat_direction = self.required_char(4)?;
+        let longitude = self.required_coordinate(5)?;
+        let lon_direction = self.required_char(6)?;
+        let speed_knots: f32 = self.required_field(7)?;
+        let track_angle: f32 = self.required_field(8)?;
+        let date = self.required_date(9)?;
+
+        Ok(RmcData {
             talker_id: self.talker_id,
-            time_data,
-            time_len,
-            status,
+            time,
+            status: Status::from_char(status),
+            status_raw: status,
             latitude,
             lat_direction,
             longitude,
             lon_direction,
             speed_knots,
             track_angle,
-            date_data,
-            date_len,
+            date,
             magnetic_variation: self.parse_field(10),
             mag_var_direction: self.parse_field_char(11),
+            mode_indicator: self.parse_field_char(12),
+            nav_status: self.parse_field_char(13),
         })
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::datetime::{NmeaDate, NmeaTime};
+    use crate::types::ErrorReason;
+    use crate::types::Status;
     use crate::NmeaParser;
 
     #[test]
@@ -186,23 +417,88 @@ mod tests {
         assert!(rmc.is_some());
 
         let rmc_data = rmc.unwrap();
-        assert_eq!(rmc_data.time(), "123519");
-        assert_eq!(rmc_data.status, 'A');
+        assert_eq!(
+            rmc_data.time(),
+            NmeaTime {
+                hour: 12,
+                minute: 35,
+                second: 19,
+                millis: 0
+            }
+        );
+        assert_eq!(rmc_data.status, Status::Valid);
         assert_eq!(rmc_data.latitude, 4807.038);
         assert_eq!(rmc_data.lat_direction, 'N');
         assert_eq!(rmc_data.longitude, 1131.000);
         assert_eq!(rmc_data.lon_direction, 'E');
         assert_eq!(rmc_data.speed_knots, 22.4);
         assert_eq!(rmc_data.track_angle, 84.4);
-        assert_eq!(rmc_data.date(), "230394");
+        assert_eq!(
+            rmc_data.date(),
+            NmeaDate {
+                year: 1994,
+                month: 3,
+                day: 23
+            }
+        );
         assert_eq!(rmc_data.magnetic_variation, Some(3.1));
         assert_eq!(rmc_data.mag_var_direction, Some('W'));
+        assert_eq!(rmc_data.mode_indicator, None);
+        assert_eq!(rmc_data.nav_status, None);
+    }
+
+    #[test]
+    fn test_rmc_with_mode_indicator() {
+        let parser = NmeaParser::new();
+        let sentence =
+            b"$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W,A*07\r\n";
+
+        let result = parser.parse_sentence_complete(sentence);
+
+        assert!(result.is_some());
+        let msg = result.unwrap();
+        let rmc_data = msg.as_rmc().unwrap();
+        assert_eq!(rmc_data.mag_var_direction, Some('W'));
+        assert_eq!(rmc_data.mode_indicator, Some('A'));
+        assert_eq!(rmc_data.nav_status, None);
+    }
+
+    #[test]
+    fn test_rmc_nmea41_mode_and_nav_status() {
+        let parser = NmeaParser::new();
+        let sentence =
+            b"$GNRMC,123519.00,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W,R,V*5E\r\n";
+
+        let result = parser.parse_sentence_complete(sentence);
+
+        assert!(result.is_some());
+        let msg = result.unwrap();
+        let rmc_data = msg.as_rmc().unwrap();
+        assert_eq!(
+            rmc_data.time(),
+            NmeaTime {
+                hour: 12,
+                minute: 35,
+                second: 19,
+                millis: 0
+            }
+        );
+        assert_eq!(
+            rmc_data.date(),
+            NmeaDate {
+                year: 1994,
+                month: 3,
+                day: 23
+            }
+        );
+        assert_eq!(rmc_data.mode_indicator, Some('R'));
+        assert_eq!(rmc_data.nav_status, Some('V'));
     }
 
     #[test]
     fn test_rmc_void_status() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPRMC,123519,V,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A\r\n";
+        let sentence = b"$GPRMC,123519,V,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*7D\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -212,13 +508,14 @@ mod tests {
         assert!(rmc.is_some());
 
         let rmc_data = rmc.unwrap();
-        assert_eq!(rmc_data.status, 'V');
+        assert_eq!(rmc_data.status, Status::Invalid);
+        assert_eq!(rmc_data.status_raw(), 'V');
     }
 
     #[test]
     fn test_rmc_without_magnetic_variation() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,,*6A\r\n";
+        let sentence = b"$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,,*11\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -235,7 +532,7 @@ mod tests {
     #[test]
     fn test_rmc_missing_time() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPRMC,,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A\r\n";
+        let sentence = b"$GPRMC,,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*67\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -246,7 +543,7 @@ mod tests {
     #[test]
     fn test_rmc_missing_status() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPRMC,123519,,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A\r\n";
+        let sentence = b"$GPRMC,123519,,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*2B\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -257,7 +554,7 @@ mod tests {
     #[test]
     fn test_rmc_missing_date() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,,003.1,W*6A\r\n";
+        let sentence = b"$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,,003.1,W*65\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -265,10 +562,29 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_rmc_invalid_time_and_date() {
+        let parser = NmeaParser::new();
+
+        // Minute 65 is out of range
+        let sentence = b"$GPRMC,126519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6F\r\n";
+        let (err, _) = parser.parse_bytes(sentence).unwrap_err();
+        let detail = err.detail().unwrap();
+        assert_eq!(detail.reason, ErrorReason::InvalidValue);
+        assert_eq!(detail.field, Some(1));
+
+        // Month 13 is out of range
+        let sentence = b"$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,231394,003.1,W*6B\r\n";
+        let (err, _) = parser.parse_bytes(sentence).unwrap_err();
+        let detail = err.detail().unwrap();
+        assert_eq!(detail.reason, ErrorReason::InvalidValue);
+        assert_eq!(detail.field, Some(9));
+    }
+
     #[test]
     fn test_rmc_missing_speed() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPRMC,123519,A,4807.038,N,01131.000,E,,084.4,230394,003.1,W*6A\r\n";
+        let sentence = b"$GPRMC,123519,A,4807.038,N,01131.000,E,,084.4,230394,003.1,W*40\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -279,7 +595,7 @@ mod tests {
     #[test]
     fn test_rmc_missing_track_angle() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,,230394,003.1,W*6A\r\n";
+        let sentence = b"$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,,230394,003.1,W*4C\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -290,7 +606,7 @@ mod tests {
     #[test]
     fn test_rmc_zero_speed() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPRMC,123519,A,4807.038,N,01131.000,E,0.0,0.0,230394,003.1,W*6A\r\n";
+        let sentence = b"$GPRMC,123519,A,4807.038,N,01131.000,E,0.0,0.0,230394,003.1,W*66\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -327,7 +643,7 @@ mod tests {
     fn test_rmc_different_talker_id() {
         let parser = NmeaParser::new();
         // GNRMC is multi-GNSS
-        let sentence = b"$GNRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A\r\n";
+        let sentence = b"$GNRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*74\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -355,7 +671,7 @@ mod tests {
 
         // Test GLONASS
         let gl_sentence =
-            b"$GLRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A\r\n";
+            b"$GLRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*76\r\n";
         let gl_result = parser.parse_sentence_complete(gl_sentence);
         assert!(gl_result.is_some());
         let gl_msg = gl_result.unwrap();
@@ -364,11 +680,67 @@ mod tests {
 
         // Test Galileo
         let ga_sentence =
-            b"$GARMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A\r\n";
+            b"$GARMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*7B\r\n";
         let ga_result = parser.parse_sentence_complete(ga_sentence);
         assert!(ga_result.is_some());
         let ga_msg = ga_result.unwrap();
         let ga_rmc = ga_msg.as_rmc().unwrap();
         assert_eq!(ga_rmc.talker_id, crate::types::TalkerId::GA);
     }
+
+    #[test]
+    fn test_rmc_builder() {
+        let (lat, lon) = (-22.9068, -43.1729);
+
+        let builder = super::RmcData::builder()
+            .talker_id(crate::types::TalkerId::GN)
+            .time(NmeaTime {
+                hour: 23,
+                minute: 59,
+                second: 59,
+                millis: 500,
+            })
+            .date(NmeaDate {
+                year: 2024,
+                month: 12,
+                day: 31,
+            })
+            .status(Status::Valid)
+            .position(lat, lon)
+            .mode_indicator('D')
+            .nav_status('V');
+
+        let mut buffer = [0u8; 82];
+        let len = builder.encode(&mut buffer).unwrap();
+        let sentence = &buffer[..len];
+        assert_eq!(
+            sentence,
+            b"$GNRMC,235959.50,A,2254.40800,S,04310.37400,W,0,0,311224,,,D,V*38\r\n"
+        );
+
+        let msg = NmeaParser::new().parse_sentence_complete(sentence).unwrap();
+        let rmc = msg.as_rmc().unwrap();
+        assert_eq!(rmc.status, Status::Valid);
+        assert_eq!(rmc.date(), builder.build().date());
+        assert_eq!(rmc.mode_indicator, Some('D'));
+        assert_eq!(rmc.nav_status, Some('V'));
+    }
+
+    #[test]
+    fn test_rmc_builder_defaults() {
+        let mut buffer = [0u8; 82];
+        let len = super::RmcBuilder::default().encode(&mut buffer).unwrap();
+        assert_eq!(
+            &buffer[..len],
+            b"$GPRMC,000000,V,0000.00000,N,00000.00000,E,0,0,000000,,*0A\r\n"
+        );
+
+        let rmc = super::RmcBuilder::new()
+            .status(Status::Unknown)
+            .magnetic_variation(2.5)
+            .build();
+        assert_eq!(rmc.status, Status::Invalid);
+        assert_eq!(rmc.magnetic_variation, Some(2.5));
+        assert_eq!(rmc.mag_var_direction, Some('E'));
+    }
 }
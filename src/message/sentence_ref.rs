@@ -0,0 +1,225 @@
+//! Zero-copy borrowed sentence
+//!
+//! [`SentenceRef`] is returned by
+//! [`NmeaParser::parse_bytes_ref`](crate::NmeaParser::parse_bytes_ref). It
+//! borrows the sentence from the input buffer instead of copying string
+//! fields into fixed arrays: fields are located on demand and returned as
+//! slices of the input.
+
+use crate::decoder::{MessageDecoder, NoDecoder};
+use crate::message::split_fields;
+use crate::number::{self, Number};
+use crate::parser::NmeaParser;
+use crate::scan;
+use crate::types::{MessageType, TalkerId};
+
+/// Complete NMEA sentence borrowed from the input buffer
+pub struct SentenceRef<'a, D = NoDecoder> {
+    /// Parser that framed the sentence, used to decode it
+    parser: &'a NmeaParser<D>,
+    talker_id: TalkerId,
+    message_type: MessageType,
+    /// Sentence from `$` to the checksum, without line ending
+    sentence: &'a [u8],
+    /// Data part between `$` and the `*` checksum marker
+    data: &'a [u8],
+}
+
+impl<'a, D: MessageDecoder> SentenceRef<'a, D> {
+    pub(crate) fn new(
+        parser: &'a NmeaParser<D>,
+        sentence: &'a [u8],
+        talker_id: TalkerId,
+        message_type: MessageType,
+    ) -> Self {
+        let data_end = scan::find(sentence, b'*').unwrap_or(sentence.len());
+
+        SentenceRef {
+            parser,
+            talker_id,
+            message_type,
+            sentence,
+            data: &sentence[1..data_end],
+        }
+    }
+
+    /// Get the talker ID
+    pub fn talker_id(&self) -> TalkerId {
+        self.talker_id
+    }
+
+    /// Get the message type
+    pub fn message_type(&self) -> MessageType {
+        self.message_type
+    }
+
+    /// Get the address field (e.g. "GPGGA") borrowed from the input
+    pub fn address(&self) -> &'a str {
+        self.raw_fields().next().unwrap_or("")
+    }
+
+    /// Number of data fields following the address field
+    pub fn field_count(&self) -> usize {
+        self.raw_fields().count() - 1
+    }
+
+    /// Get a data field borrowed from the input (index 0 is the first field after the address)
+    ///
+    /// Returns `None` if the field is empty, missing or not valid UTF-8.
+    pub fn field(&self, index: usize) -> Option<&'a str> {
+        self.raw_fields()
+            .nth(index + 1)
+            .filter(|field| !field.is_empty())
+    }
+
+    /// Parse a numeric data field (index 0 is the first field after the address)
+    ///
+    /// Numbers are parsed like the fields of the built-in messages, also with
+    /// the `compact-numbers` feature.
+    pub fn parse_field<T: Number>(&self, index: usize) -> Option<T> {
+        number::parse(self.field(index)?)
+    }
+
+    /// Iterate over the data fields following the address field
+    ///
+    /// Empty fields are yielded as empty strings.
+    pub fn fields(&self) -> impl Iterator<Item = &'a str> {
+        self.raw_fields().skip(1)
+    }
+
+    /// Get the raw sentence bytes (from `$` to the checksum, without line ending)
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.sentence
+    }
+
+    /// Decode the sentence into an owned typed message
+    ///
+    /// The sentence is decoded with the configuration, including the custom
+    /// decoder, of the parser that returned it. Returns `None` if mandatory
+    /// fields are missing or invalid.
+    pub fn to_message(&self) -> Option<D::Message> {
+        let msg = self.parser.parse_sentence(self.sentence).ok()?;
+        self.parser.decoder().decode_message(msg).ok()
+    }
+
+    /// Iterate over all comma-separated fields, starting with the address
+    fn raw_fields(&self) -> impl Iterator<Item = &'a str> {
+        split_fields(self.data)
+    }
+}
+
+impl<D> Clone for SentenceRef<'_, D> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<D> Copy for SentenceRef<'_, D> {}
+
+impl<D> core::fmt::Debug for SentenceRef<'_, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SentenceRef")
+            .field("talker_id", &self.talker_id)
+            .field("message_type", &self.message_type)
+            .field("sentence", &self.sentence)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::datetime::NmeaTime;
+    use crate::types::{MessageType, ParseError, TalkerId};
+    use crate::NmeaParser;
+
+    #[test]
+    fn test_borrowed_gga_fields() {
+        let parser = NmeaParser::new();
+        let data = b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n";
+
+        let (sentence, consumed) = parser.parse_bytes_ref(data).unwrap();
+        let sentence = sentence.unwrap();
+        assert_eq!(consumed, data.len());
+        assert_eq!(sentence.talker_id(), TalkerId::GP);
+        assert_eq!(sentence.message_type(), MessageType::GGA);
+        assert_eq!(sentence.address(), "GPGGA");
+        assert_eq!(sentence.field_count(), 14);
+
+        // The time field points into the input buffer
+        let time = sentence.field(0).unwrap();
+        assert_eq!(time, "123519");
+        assert_eq!(time.as_ptr(), data[7..].as_ptr());
+
+        assert_eq!(sentence.parse_field::<f64>(1), Some(4807.038));
+        assert_eq!(sentence.parse_field::<u8>(6), Some(8));
+        assert_eq!(sentence.parse_field::<u8>(2), None);
+        assert_eq!(sentence.field(12), None);
+    }
+
+    #[test]
+    fn test_borrowed_to_message() {
+        let parser = NmeaParser::new();
+        let data = b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+
+        let (sentence, _) = parser.parse_bytes_ref(data).unwrap();
+        let msg = sentence.unwrap().to_message().unwrap();
+        assert_eq!(
+            msg.as_gll().unwrap().time(),
+            NmeaTime {
+                hour: 22,
+                minute: 54,
+                second: 44,
+                millis: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_borrowed_to_message_uses_parser_decoder() {
+        use crate::{GenericSentence, SentenceDecoder};
+
+        struct WaterTemperature;
+
+        impl SentenceDecoder for WaterTemperature {
+            type Output = f32;
+
+            fn accepts(&self, sentence: &GenericSentence) -> bool {
+                sentence.sentence_formatter() == "MTW"
+            }
+
+            fn decode(&self, sentence: &GenericSentence) -> Option<f32> {
+                sentence.field(0)?.parse().ok()
+            }
+        }
+
+        let parser = NmeaParser::builder().decoder(WaterTemperature).build();
+        let (sentence, _) = parser.parse_bytes_ref(b"$IIMTW,18.5,C*1F\r\n").unwrap();
+        let decoded = sentence.unwrap().to_message().unwrap();
+        assert_eq!(decoded.as_custom(), Some(&18.5));
+    }
+
+    #[test]
+    fn test_borrowed_checksum_and_partial() {
+        let parser = NmeaParser::new();
+
+        let data = b"$GPGLL,4916.45,N,12311.12,W,225444,A,*00\r\n";
+        let (err, consumed) = parser.parse_bytes_ref(data).unwrap_err();
+        assert!(matches!(err, ParseError::InvalidChecksum(_)));
+        assert_eq!(consumed, data.len());
+
+        let (sentence, consumed) = parser.parse_bytes_ref(b"xx$GPGLL,49").unwrap();
+        assert!(sentence.is_none());
+        assert_eq!(consumed, 2);
+    }
+
+    #[test]
+    fn test_borrowed_unknown_sentence() {
+        let parser = NmeaParser::new();
+        let data = b"$GPTXT,01,01,02,ANTSTATUS=OK*3B\r\n";
+
+        let (sentence, _) = parser.parse_bytes_ref(data).unwrap();
+        let sentence = sentence.unwrap();
+        assert_eq!(sentence.message_type(), MessageType::Unknown);
+        assert_eq!(sentence.field(3), Some("ANTSTATUS=OK"));
+    }
+}
@@ -54,11 +54,14 @@
 //! - Speed: 5.5 knots = 10.2 km/h
 //! - Magnetic variation: ~20° East (54.7 - 34.4)
 
+use crate::encode::{EncodeError, SentenceWriter};
 use crate::message::ParsedSentence;
-use crate::types::{MessageType, TalkerId};
+use crate::types::{ErrorDetail, MessageType, TalkerId};
 
 /// VTG - Track Made Good and Ground Speed parameters
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct VtgData {
     pub talker_id: TalkerId,
     pub track_true: Option<f32>,
@@ -71,7 +74,23 @@ pub struct VtgData {
     pub speed_kph_indicator: Option<char>,
 }
 
-impl ParsedSentence {
+impl VtgData {
+    /// Encode as a VTG sentence into `buffer`, returning the sentence length
+    pub fn encode(&self, buffer: &mut [u8]) -> Result<usize, EncodeError> {
+        let mut w = SentenceWriter::new(buffer, self.talker_id, "VTG")?;
+        w.field(self.track_true)?;
+        w.field(self.track_true_indicator)?;
+        w.field(self.track_magnetic)?;
+        w.field(self.track_magnetic_indicator)?;
+        w.field(self.speed_knots)?;
+        w.field(self.speed_knots_indicator)?;
+        w.field(self.speed_kph)?;
+        w.field(self.speed_kph_indicator)?;
+        w.finish()
+    }
+}
+
+impl ParsedSentence<'_> {
     /// Extract VTG message parameters
     ///
     /// Parses the VTG (Track Made Good and Ground Speed) message and returns
@@ -79,8 +98,8 @@ impl ParsedSentence {
     ///
     /// # Returns
     ///
-    /// - `Some(VtgData)` if the message is a valid VTG message
-    /// - `None` if the message is not a VTG message
+    /// - `Ok(VtgData)` if the message is a valid VTG message
+    /// - `Err(ErrorDetail)` if the message is not a VTG message
     ///
     /// # Mandatory Fields
     ///
@@ -111,12 +130,10 @@ impl ParsedSentence {
     ///     }
     /// }
     /// ```
-    pub fn as_vtg(&self) -> Option<VtgData> {
-        if self.message_type != MessageType::VTG {
-            return None;
-        }
+    pub fn as_vtg(&self) -> Result<VtgData, ErrorDetail> {
+        self.expect_type(MessageType::VTG)?;
 
-        Some(VtgData {
+        Ok(VtgData {
             talker_id: self.talker_id,
             track_true: self.parse_field(1),
             track_true_indicator: self.parse_field_char(2),
@@ -160,7 +177,7 @@ mod tests {
     #[test]
     fn test_vtg_with_empty_fields() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPVTG,,T,,M,,N,,K*48\r\n";
+        let sentence = b"$GPVTG,,T,,M,,N,,K*4E\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -179,7 +196,7 @@ mod tests {
     #[test]
     fn test_vtg_zero_speed() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPVTG,0.0,T,0.0,M,0.0,N,0.0,K*48\r\n";
+        let sentence = b"$GPVTG,0.0,T,0.0,M,0.0,N,0.0,K*4E\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -196,7 +213,7 @@ mod tests {
     #[test]
     fn test_vtg_high_speed() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPVTG,270.5,T,250.3,M,125.8,N,233.0,K*48\r\n";
+        let sentence = b"$GPVTG,270.5,T,250.3,M,125.8,N,233.0,K*46\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -213,7 +230,7 @@ mod tests {
     #[test]
     fn test_vtg_only_true_track() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPVTG,054.7,T,,M,,N,,K*48\r\n";
+        let sentence = b"$GPVTG,054.7,T,,M,,N,,K*66\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -230,7 +247,7 @@ mod tests {
     #[test]
     fn test_vtg_only_knots() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPVTG,,T,,M,5.5,N,,K*48\r\n";
+        let sentence = b"$GPVTG,,T,,M,5.5,N,,K*60\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -247,7 +264,7 @@ mod tests {
     #[test]
     fn test_vtg_only_kph() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPVTG,,T,,M,,N,10.2,K*48\r\n";
+        let sentence = b"$GPVTG,,T,,M,,N,10.2,K*53\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -266,7 +283,7 @@ mod tests {
         let parser = NmeaParser::new();
 
         // Test 0 degrees
-        let sentence = b"$GPVTG,0.0,T,0.0,M,5.5,N,10.2,K*48\r\n";
+        let sentence = b"$GPVTG,0.0,T,0.0,M,5.5,N,10.2,K*7D\r\n";
         let result = parser.parse_sentence_complete(sentence);
         assert!(result.is_some());
         let msg = result.unwrap();
@@ -274,7 +291,7 @@ mod tests {
         assert_eq!(vtg.track_true, Some(0.0));
 
         // Test 359.9 degrees
-        let sentence = b"$GPVTG,359.9,T,359.9,M,5.5,N,10.2,K*48\r\n";
+        let sentence = b"$GPVTG,359.9,T,359.9,M,5.5,N,10.2,K*7D\r\n";
         let result = parser.parse_sentence_complete(sentence);
         assert!(result.is_some());
         let msg = result.unwrap();
@@ -328,7 +345,7 @@ mod tests {
     fn test_vtg_different_talker_id() {
         let parser = NmeaParser::new();
         // GNVTG is multi-GNSS
-        let sentence = b"$GNVTG,054.7,T,034.4,M,005.5,N,010.2,K*48\r\n";
+        let sentence = b"$GNVTG,054.7,T,034.4,M,005.5,N,010.2,K*56\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -355,7 +372,7 @@ mod tests {
         assert_eq!(gp_vtg.track_true, Some(54.7));
 
         // Test GLONASS
-        let gl_sentence = b"$GLVTG,154.7,T,134.4,M,015.5,N,028.7,K*48\r\n";
+        let gl_sentence = b"$GLVTG,154.7,T,134.4,M,015.5,N,028.7,K*5B\r\n";
         let gl_result = parser.parse_sentence_complete(gl_sentence);
         assert!(gl_result.is_some());
         let gl_msg = gl_result.unwrap();
@@ -367,7 +384,7 @@ mod tests {
     #[test]
     fn test_vtg_easterly_heading() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPVTG,090.0,T,085.0,M,10.0,N,18.5,K*48\r\n";
+        let sentence = b"$GPVTG,090.0,T,085.0,M,10.0,N,18.5,K*47\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -383,7 +400,7 @@ mod tests {
     #[test]
     fn test_vtg_westerly_heading() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPVTG,270.0,T,265.0,M,10.0,N,18.5,K*48\r\n";
+        let sentence = b"$GPVTG,270.0,T,265.0,M,10.0,N,18.5,K*47\r\n";
 
         let result = parser.parse_sentence_complete(sentence);
 
@@ -0,0 +1,185 @@
+//! Numeric field parsing
+//!
+//! All numeric NMEA fields go through [`parse`]. By default it delegates to
+//! `FromStr`. With the `compact-numbers` feature it uses a small parser
+//! limited to the plain decimal notation NMEA uses (`[+-]digits[.digits]`,
+//! no exponent, `inf` or `nan`), which avoids linking the much larger
+//! general-purpose float parsing code of `core`.
+//!
+//! The compact float parser is exact for values with up to 15 significant
+//! digits (f64) or 7 significant digits (f32), which covers every NMEA field.
+//! Fraction digits beyond the 19th significant digit are ignored.
+
+/// Numeric type that can be parsed from an NMEA field
+///
+/// Implemented for the primitive integer and float types; the parsing honours
+/// the `compact-numbers` feature like the fields of the built-in messages.
+/// This trait is sealed and cannot be implemented outside the crate.
+pub trait Number: Sized + private::Sealed {
+    #[doc(hidden)]
+    fn parse_number(s: &str) -> Option<Self>;
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for u8 {}
+    impl Sealed for u16 {}
+    impl Sealed for u32 {}
+    impl Sealed for u64 {}
+    impl Sealed for i8 {}
+    impl Sealed for i16 {}
+    impl Sealed for i32 {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// Parse a numeric NMEA field
+pub(crate) fn parse<T: Number>(s: &str) -> Option<T> {
+    T::parse_number(s)
+}
+
+#[cfg(not(feature = "compact-numbers"))]
+macro_rules! impl_number {
+    ($($t:ty),*) => {
+        $(impl Number for $t {
+            fn parse_number(s: &str) -> Option<Self> {
+                s.parse().ok()
+            }
+        })*
+    };
+}
+
+#[cfg(not(feature = "compact-numbers"))]
+impl_number!(u8, u16, u32, u64, i8, i16, i32, f32, f64);
+
+#[cfg(feature = "compact-numbers")]
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(impl Number for $t {
+            fn parse_number(s: &str) -> Option<Self> {
+                let (negative, mantissa, scale) = parse_decimal(s)?;
+                if scale != 0 || s.ends_with('.') {
+                    return None;
+                }
+                let value = i128::from(mantissa);
+                Self::try_from(if negative { -value } else { value }).ok()
+            }
+        })*
+    };
+}
+
+#[cfg(feature = "compact-numbers")]
+impl_integer!(u8, u16, u32, u64, i8, i16, i32);
+
+#[cfg(feature = "compact-numbers")]
+const POW10: [f64; 20] = [
+    1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15, 1e16,
+    1e17, 1e18, 1e19,
+];
+
+#[cfg(feature = "compact-numbers")]
+impl Number for f64 {
+    fn parse_number(s: &str) -> Option<Self> {
+        let (negative, mantissa, scale) = parse_decimal(s)?;
+        let value = mantissa as f64 / POW10[scale];
+        Some(if negative { -value } else { value })
+    }
+}
+
+#[cfg(feature = "compact-numbers")]
+impl Number for f32 {
+    fn parse_number(s: &str) -> Option<Self> {
+        let (negative, mantissa, scale) = parse_decimal(s)?;
+        // A single f32 division is correctly rounded while both operands are
+        // exact, otherwise go through f64 to keep the error below one ulp
+        let value = if mantissa < 1 << 24 && scale <= 10 {
+            mantissa as f32 / POW10[scale] as f32
+        } else {
+            (mantissa as f64 / POW10[scale]) as f32
+        };
+        Some(if negative { -value } else { value })
+    }
+}
+
+/// Split a decimal number into sign, digits and number of fraction digits
+///
+/// Returns `None` unless the input is an optional sign followed by digits
+/// with at most one decimal point and at least one digit overall.
+#[cfg(feature = "compact-numbers")]
+fn parse_decimal(s: &str) -> Option<(bool, u64, usize)> {
+    let bytes = s.as_bytes();
+    let (negative, digits) = match bytes.first()? {
+        b'-' => (true, &bytes[1..]),
+        b'+' => (false, &bytes[1..]),
+        _ => (false, bytes),
+    };
+
+    let mut mantissa: u64 = 0;
+    let mut scale = 0;
+    let mut seen_digit = false;
+    let mut in_fraction = false;
+
+    for &b in digits {
+        match b {
+            b'0'..=b'9' => {
+                seen_digit = true;
+                let d = u64::from(b - b'0');
+                match mantissa.checked_mul(10).and_then(|m| m.checked_add(d)) {
+                    Some(m) if scale < POW10.len() - 1 => {
+                        mantissa = m;
+                        if in_fraction {
+                            scale += 1;
+                        }
+                    }
+                    // Out of precision: drop extra fraction digits
+                    _ if in_fraction => {}
+                    _ => return None,
+                }
+            }
+            b'.' if !in_fraction => in_fraction = true,
+            _ => return None,
+        }
+    }
+
+    seen_digit.then_some((negative, mantissa, scale))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_integers() {
+        assert_eq!(parse::<u8>("08"), Some(8));
+        assert_eq!(parse::<u8>("255"), Some(255));
+        assert_eq!(parse::<u8>("256"), None);
+        assert_eq!(parse::<u16>("359"), Some(359));
+        assert_eq!(parse::<u32>("4294967295"), Some(u32::MAX));
+        assert_eq!(parse::<u64>("1577836800"), Some(1_577_836_800));
+        assert_eq!(parse::<i8>("-12"), Some(-12));
+        assert_eq!(parse::<u8>("-1"), None);
+    }
+
+    #[test]
+    fn test_parse_floats() {
+        assert_eq!(parse::<f32>("0.9"), Some(0.9));
+        assert_eq!(parse::<f32>("545.4"), Some(545.4));
+        assert_eq!(parse::<f32>("-46.9"), Some(-46.9));
+        assert_eq!(parse::<f32>("022.4"), Some(22.4));
+        assert_eq!(parse::<f64>("4807.038"), Some(4807.038));
+        assert_eq!(parse::<f64>("12311.125678"), Some(12311.125678));
+        assert_eq!(parse::<f64>("1005.543"), Some(1005.543));
+        assert_eq!(parse::<f64>("5."), Some(5.0));
+        assert_eq!(parse::<f64>(".5"), Some(0.5));
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed() {
+        for input in ["", "-", ".", "1.2.3", "12a", " 1", "1 ", "0x10"] {
+            assert_eq!(parse::<f64>(input), None, "{input:?}");
+            assert_eq!(parse::<u32>(input), None, "{input:?}");
+        }
+        assert_eq!(parse::<u8>("1.5"), None);
+    }
+}
@@ -1,15 +1,206 @@
 //! NMEA sentence parser implementation
 
-use crate::message::{Field, ParsedSentence, MAX_FIELDS};
-use crate::types::{MessageType, NmeaMessage, ParseError, TalkerId};
+mod iter;
+
+pub use iter::NmeaIter;
+
+use crate::checksum;
+use crate::clock::Timestamped;
+use crate::decoder::{MessageDecoder, NoDecoder, SentenceDecoder};
+use crate::handler::{self, MessageHandler};
+use crate::message::{
+    GenericSentence, ParsedSentence, ProprietaryData, SentenceRef, MAX_GENERIC_LEN,
+};
+use crate::scan;
+use crate::tag_block::TagBlock;
+use crate::types::{
+    BufferHint, ChecksumPolicy, ErrorDetail, ErrorReason, MessageType, NmeaMessage, ParseError,
+    TalkerId,
+};
+use crate::ubx::{self, UbxFrame};
+use crate::{
+    HintedParseResult, ParseResult, RawParseResult, RefParseResult, TaggedParseResult,
+    TimestampedParseResult,
+};
+
+/// Maximum number of talkers that can be registered in a talker filter
+pub const MAX_ACCEPTED_TALKERS: usize = 8;
+
+/// Default maximum sentence length, including `$` and the `<CR><LF>` line ending
+///
+/// NMEA 0183 limits sentences to 82 characters.
+pub const DEFAULT_MAX_SENTENCE_LEN: usize = 82;
+
+/// Outcome of locating the next sentence in a byte buffer
+enum Frame<'a> {
+    /// No complete sentence yet; the given number of spurious bytes can be consumed
+    Incomplete(usize),
+    /// Complete sentence (without line ending) and the content of its TAG block
+    Sentence {
+        tag: Option<&'a [u8]>,
+        sentence: &'a [u8],
+        consumed: usize,
+    },
+    /// Sentence exceeding the maximum length; the given number of bytes can be consumed
+    Overlong(usize),
+}
 
 /// Main NMEA parser structure (now stateless)
-pub struct NmeaParser {}
+///
+/// The parser keeps no data between calls; it only holds its configuration.
+/// A parser built with a custom `SentenceDecoder` (see
+/// `NmeaParserBuilder::decoder`) returns `Decoded` messages instead of
+/// `NmeaMessage`s.
+pub struct NmeaParser<D = NoDecoder> {
+    checksum_policy: ChecksumPolicy,
+    message_filter: Option<u16>,
+    talker_filter: [Option<TalkerId>; MAX_ACCEPTED_TALKERS],
+    max_sentence_len: usize,
+    skip_ubx: bool,
+    decoder: D,
+}
 
 impl NmeaParser {
     /// Create a new NMEA parser instance
+    ///
+    /// The parser uses `ChecksumPolicy::Lenient`: sentences without checksum
+    /// are accepted, sentences with a wrong checksum are rejected.
     pub fn new() -> Self {
-        NmeaParser {}
+        NmeaParserBuilder::new().build()
+    }
+
+    /// Create a builder to configure a new parser
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustedbytes_nmea::{ChecksumPolicy, NmeaParser};
+    ///
+    /// let parser = NmeaParser::builder()
+    ///     .checksum_policy(ChecksumPolicy::Strict)
+    ///     .build();
+    /// assert_eq!(parser.checksum_policy(), ChecksumPolicy::Strict);
+    /// ```
+    pub fn builder() -> NmeaParserBuilder {
+        NmeaParserBuilder::new()
+    }
+}
+
+impl<D: MessageDecoder> NmeaParser<D> {
+    /// Set the checksum verification policy
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustedbytes_nmea::{ChecksumPolicy, NmeaParser, ParseError};
+    ///
+    /// let parser = NmeaParser::new().with_checksum_policy(ChecksumPolicy::Strict);
+    ///
+    /// // Strict policy rejects sentences without checksum
+    /// let result = parser.parse_bytes(b"$GPGLL,4916.45,N,12311.12,W,225444,A,\r\n");
+    /// assert!(matches!(result, Err((ParseError::InvalidChecksum(_), _))));
+    /// ```
+    pub fn with_checksum_policy(mut self, policy: ChecksumPolicy) -> Self {
+        self.checksum_policy = policy;
+        self
+    }
+
+    /// Get the registered decoder
+    pub fn decoder(&self) -> &D {
+        &self.decoder
+    }
+
+    /// Get the checksum verification policy
+    pub fn checksum_policy(&self) -> ChecksumPolicy {
+        self.checksum_policy
+    }
+
+    /// Get the maximum sentence length, including `$` and the line ending
+    pub fn max_sentence_length(&self) -> usize {
+        self.max_sentence_len
+    }
+
+    /// Check whether interleaved UBX binary frames are skipped as a whole
+    pub fn skips_ubx_frames(&self) -> bool {
+        self.skip_ubx
+    }
+
+    /// Check whether sentences of the given message type are decoded
+    ///
+    /// Returns `true` for every message type unless a message-type filter
+    /// was configured with `NmeaParserBuilder::accept_message_type`.
+    pub fn accepts_message_type(&self, message_type: MessageType) -> bool {
+        match self.message_filter {
+            Some(mask) => mask & message_type.mask() != 0,
+            None => true,
+        }
+    }
+
+    /// Iterate over the messages contained in a byte buffer
+    ///
+    /// Repeatedly applies `parse_bytes`, yielding the outcome of each complete
+    /// sentence together with the offset just past it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustedbytes_nmea::NmeaParser;
+    ///
+    /// let parser = NmeaParser::new();
+    /// let data = b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n\
+    ///              $GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+    ///
+    /// for (result, _offset) in parser.iter(data) {
+    ///     if let Ok(msg) = result {
+    ///         println!("{:?}", msg.message_type());
+    ///     }
+    /// }
+    /// ```
+    pub fn iter<'d>(&self, data: &'d [u8]) -> NmeaIter<'_, 'd, D> {
+        NmeaIter::new(self, data)
+    }
+
+    /// Parse as many complete messages as fit into `out`
+    ///
+    /// Messages are stored in order from `out[0]`; invalid and skipped
+    /// sentences and spurious data are consumed and discarded. Parsing stops
+    /// when `out` is full, at the end of `data`, or at a trailing partial
+    /// sentence.
+    ///
+    /// Returns the number of messages stored and the number of bytes consumed.
+    /// Bytes after `bytes_consumed` must be passed again with the next chunk.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustedbytes_nmea::{NmeaMessage, NmeaParser};
+    ///
+    /// let parser = NmeaParser::new();
+    /// let data = b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n\
+    ///              $GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n$GPGSA,A,3";
+    ///
+    /// let mut out: [Option<NmeaMessage>; 4] = Default::default();
+    /// let (count, consumed) = parser.parse_all(data, &mut out);
+    ///
+    /// assert_eq!(count, 2);
+    /// assert_eq!(&data[consumed..], b"$GPGSA,A,3");
+    /// ```
+    pub fn parse_all(&self, data: &[u8], out: &mut [Option<D::Message>]) -> (usize, usize) {
+        let mut count = 0;
+        let mut iter = self.iter(data);
+
+        while count < out.len() {
+            match iter.next() {
+                Some((Ok(msg), _offset)) => {
+                    out[count] = Some(msg);
+                    count += 1;
+                }
+                Some((Err(_), _offset)) => {}
+                None => break,
+            }
+        }
+
+        (count, iter.consumed())
     }
 
     /// Parse multiple bytes and return a parsed message if found, along with bytes consumed
@@ -20,106 +211,529 @@ impl NmeaParser {
     /// - Err((ParseError, bytes_consumed)) - Found complete message but it's invalid
     ///
     /// The parser handles spurious characters before the '$' start marker by consuming them.
-    pub fn parse_bytes(
+    /// A NMEA 4.10 TAG block preceding the sentence is accepted and discarded; use
+    /// [`NmeaParser::parse_bytes_tagged`] to retrieve it.
+    pub fn parse_bytes(&self, data: &[u8]) -> ParseResult<D::Message> {
+        self.parse_bytes_tagged(data)
+            .map(|(msg, _tag, consumed)| (msg, consumed))
+    }
+
+    /// Parse multiple bytes like [`NmeaParser::parse_bytes`], also returning the
+    /// NMEA 4.10 TAG block that preceded the sentence
+    ///
+    /// Returns:
+    /// - Ok((Some(message), tag, bytes_consumed)) - Successfully parsed a complete message;
+    ///   `tag` is `Some` if a valid TAG block (`\...\`) directly preceded the sentence
+    /// - Ok((None, None, bytes_consumed)) - Partial message or TAG block, need more data
+    /// - Err((ParseError, bytes_consumed)) - Found complete message but it's invalid
+    ///
+    /// A TAG block with a wrong checksum or malformed parameters is reported as `None`
+    /// while the following sentence is still parsed. A TAG block that is not followed
+    /// by a `$` sentence is consumed as spurious data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustedbytes_nmea::{NmeaParser, MessageType};
+    ///
+    /// let parser = NmeaParser::new();
+    /// let data = b"\\s:r3669961,c:1120959341*7B\\$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+    ///
+    /// let (msg, tag, _consumed) = parser.parse_bytes_tagged(data).unwrap();
+    /// assert_eq!(msg.unwrap().message_type(), MessageType::GLL);
+    ///
+    /// let tag = tag.unwrap();
+    /// assert_eq!(tag.source(), Some("r3669961"));
+    /// assert_eq!(tag.timestamp, Some(1120959341));
+    /// ```
+    pub fn parse_bytes_tagged(&self, data: &[u8]) -> TaggedParseResult<D::Message> {
+        match self.next_frame(data) {
+            Frame::Incomplete(consumed) => Ok((None, None, consumed)),
+            Frame::Overlong(consumed) => Err((self.overlong_error(), consumed)),
+            Frame::Sentence {
+                tag,
+                sentence,
+                consumed,
+            } => match self.decode(sentence) {
+                Ok(msg) => Ok((Some(msg), tag.and_then(TagBlock::parse), consumed)),
+                Err(err) => Err((err, consumed)),
+            },
+        }
+    }
+
+    /// Parse multiple bytes like [`NmeaParser::parse_bytes`], stamping the
+    /// message with the local reception time `now_ms`
+    ///
+    /// `now_ms` is typically read from a monotonic tick when the data was
+    /// received; it is carried unchanged in `Timestamped::timestamp_ms`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustedbytes_nmea::{MessageType, NmeaParser};
+    ///
+    /// let parser = NmeaParser::new();
+    /// let data = b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+    ///
+    /// let (msg, _consumed) = parser.parse_bytes_with_timestamp(data, 42_000).unwrap();
+    /// let msg = msg.unwrap();
+    /// assert_eq!(msg.message.message_type(), MessageType::GLL);
+    /// assert_eq!(msg.timestamp_ms, 42_000);
+    /// ```
+    pub fn parse_bytes_with_timestamp(
         &self,
         data: &[u8],
-    ) -> Result<(Option<NmeaMessage>, usize), (ParseError, usize)> {
-        // Find the start of a message
-        let start_pos = data.iter().position(|&b| b == b'$');
+        now_ms: u64,
+    ) -> TimestampedParseResult<D::Message> {
+        self.parse_bytes(data).map(|(msg, consumed)| {
+            let msg = msg.map(|message| Timestamped {
+                message,
+                timestamp_ms: now_ms,
+            });
+            (msg, consumed)
+        })
+    }
+
+    /// Parse multiple bytes like [`NmeaParser::parse_bytes`], also returning the
+    /// raw bytes of the parsed sentence
+    ///
+    /// Returns:
+    /// - Ok((Some(message), Some(raw), bytes_consumed)) - Successfully parsed a complete message;
+    ///   `raw` is the sentence within `data`, from `$` to the checksum, without
+    ///   TAG block and line ending
+    /// - Ok((None, None, bytes_consumed)) - Partial message, need more data
+    /// - Err((ParseError, bytes_consumed)) - Found complete message but it's invalid
+    ///
+    /// The raw bytes can be logged, forwarded or checked again after decoding.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustedbytes_nmea::NmeaParser;
+    ///
+    /// let parser = NmeaParser::new();
+    /// let data = b"xx$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+    ///
+    /// let (msg, raw, consumed) = parser.parse_bytes_raw(data).unwrap();
+    /// assert!(msg.is_some());
+    /// assert_eq!(raw, Some(&b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D"[..]));
+    /// assert_eq!(consumed, data.len());
+    /// ```
+    pub fn parse_bytes_raw<'a>(&self, data: &'a [u8]) -> RawParseResult<'a, D::Message> {
+        match self.next_frame(data) {
+            Frame::Incomplete(consumed) => Ok((None, None, consumed)),
+            Frame::Overlong(consumed) => Err((self.overlong_error(), consumed)),
+            Frame::Sentence {
+                sentence, consumed, ..
+            } => match self.decode(sentence) {
+                Ok(msg) => Ok((Some(msg), Some(sentence), consumed)),
+                Err(err) => Err((err, consumed)),
+            },
+        }
+    }
+
+    /// Parse multiple bytes like [`NmeaParser::parse_bytes`], also hinting how
+    /// much of a partial sentence is still missing
+    ///
+    /// Returns:
+    /// - Ok((Some(message), hint)) - Successfully parsed a complete message,
+    ///   `hint.discard` bytes were consumed
+    /// - Ok((None, hint)) - Partial message, need more data; `hint.discard` leading
+    ///   bytes are spurious data that can be dropped before the next call
+    /// - Err((ParseError, bytes_consumed)) - Found complete message but it's invalid
+    ///
+    /// `hint.min_needed` is derived once the `*` checksum marker of the partial
+    /// sentence is buffered: the missing checksum digits plus the line ending.
+    /// Callers can wait for that many bytes instead of scanning the same
+    /// partial sentence again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustedbytes_nmea::NmeaParser;
+    ///
+    /// let parser = NmeaParser::new();
+    /// let data = b"noise$GPGLL,4916.45,N,12311.12,W,225444,A,*1";
+    ///
+    /// let (msg, hint) = parser.parse_bytes_hinted(data).unwrap();
+    /// assert!(msg.is_none());
+    /// assert_eq!(hint.discard, 5);
+    /// // One checksum digit and the line ending are missing
+    /// assert_eq!(hint.min_needed, Some(2));
+    /// ```
+    pub fn parse_bytes_hinted(&self, data: &[u8]) -> HintedParseResult<D::Message> {
+        match self.next_frame(data) {
+            Frame::Incomplete(consumed) => Ok((
+                None,
+                BufferHint {
+                    discard: consumed,
+                    min_needed: min_needed(&data[consumed..]),
+                },
+            )),
+            Frame::Overlong(consumed) => Err((self.overlong_error(), consumed)),
+            Frame::Sentence {
+                sentence, consumed, ..
+            } => match self.decode(sentence) {
+                Ok(msg) => Ok((
+                    Some(msg),
+                    BufferHint {
+                        discard: consumed,
+                        min_needed: None,
+                    },
+                )),
+                Err(err) => Err((err, consumed)),
+            },
+        }
+    }
+
+    /// Parse multiple bytes like [`NmeaParser::parse_bytes`] without copying:
+    /// the returned `SentenceRef` borrows the sentence from `data`
+    ///
+    /// Returns:
+    /// - Ok((Some(sentence), bytes_consumed)) - Found a complete sentence with a valid checksum
+    /// - Ok((None, bytes_consumed)) - Partial message, need more data
+    /// - Err((ParseError, bytes_consumed)) - Found complete sentence but it's invalid
+    ///
+    /// Fields are not decoded, so sentences with missing mandatory fields and of
+    /// unsupported types are returned as well; call `SentenceRef::to_message()`
+    /// for a typed message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustedbytes_nmea::{MessageType, NmeaParser};
+    ///
+    /// let parser = NmeaParser::new();
+    /// let data = b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n";
+    ///
+    /// let (sentence, _consumed) = parser.parse_bytes_ref(data).unwrap();
+    /// let sentence = sentence.unwrap();
+    /// assert_eq!(sentence.message_type(), MessageType::GGA);
+    /// assert_eq!(sentence.field(0), Some("123519"));
+    /// ```
+    pub fn parse_bytes_ref<'a>(&'a self, data: &'a [u8]) -> RefParseResult<'a, D> {
+        match self.next_frame(data) {
+            Frame::Incomplete(consumed) => Ok((None, consumed)),
+            Frame::Overlong(consumed) => Err((self.overlong_error(), consumed)),
+            Frame::Sentence {
+                sentence, consumed, ..
+            } => {
+                if !self.accepts_header(sentence) {
+                    return Err((ParseError::Skipped, consumed));
+                }
+                if let Err(detail) = checksum::verify(sentence, self.checksum_policy) {
+                    return Err((ParseError::InvalidChecksum(detail), consumed));
+                }
+                if sentence.len() < 7 {
+                    let detail = ErrorDetail::in_field(ErrorReason::UnknownType, 0, 1);
+                    return Err((ParseError::InvalidMessage(detail), consumed));
+                }
+
+                let (talker_id, message_type) = self.identify_message(&sentence[1..6]);
+                Ok((
+                    Some(SentenceRef::new(self, sentence, talker_id, message_type)),
+                    consumed,
+                ))
+            }
+        }
+    }
+
+    /// Parse all complete sentences in a buffer, dispatching them to `handler`
+    ///
+    /// Decoded messages are passed to the matching `on_*` method, sentences of
+    /// unsupported types to `MessageHandler::on_unknown` and invalid sentences
+    /// to `MessageHandler::on_error`. Message types for which
+    /// `MessageHandler::wants` returns `false` are consumed without field
+    /// extraction. Sentences filtered out by the parser configuration are
+    /// consumed silently.
+    ///
+    /// Returns the number of bytes consumed; a trailing partial sentence is
+    /// left unconsumed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustedbytes_nmea::{GgaData, MessageHandler, NmeaParser};
+    ///
+    /// struct Altitude(Option<f32>);
+    ///
+    /// impl MessageHandler for Altitude {
+    ///     fn on_gga(&mut self, gga: &GgaData) {
+    ///         self.0 = gga.altitude;
+    ///     }
+    /// }
+    ///
+    /// let parser = NmeaParser::new();
+    /// let data = b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n";
+    ///
+    /// let mut handler = Altitude(None);
+    /// let consumed = parser.parse_into(data, &mut handler);
+    ///
+    /// assert_eq!(consumed, data.len());
+    /// assert_eq!(handler.0, Some(545.4));
+    /// ```
+    pub fn parse_into<H: MessageHandler>(&self, data: &[u8], handler: &mut H) -> usize {
+        let mut offset = 0;
+
+        while offset < data.len() {
+            match self.next_frame(&data[offset..]) {
+                Frame::Incomplete(0) => break,
+                Frame::Incomplete(consumed) => offset += consumed,
+                Frame::Overlong(consumed) => {
+                    offset += consumed;
+                    handler.on_error(self.overlong_error());
+                }
+                Frame::Sentence {
+                    sentence, consumed, ..
+                } => {
+                    offset += consumed;
+                    self.dispatch_sentence(sentence, handler);
+                }
+            }
+        }
+
+        offset
+    }
+
+    /// Decode a complete sentence and pass the outcome to `handler`
+    fn dispatch_sentence<H: MessageHandler>(&self, sentence: &[u8], handler: &mut H) {
+        if !self.accepts_header(sentence) {
+            return;
+        }
+
+        if let Err(detail) = checksum::verify(sentence, self.checksum_policy) {
+            handler.on_error(ParseError::InvalidChecksum(detail));
+            return;
+        }
+
+        let message_type = if sentence.len() >= 6 {
+            self.identify_message(&sentence[1..6]).1
+        } else {
+            MessageType::Unknown
+        };
+
+        if !handler.wants(message_type) {
+            return;
+        }
+
+        if message_type == MessageType::Unknown {
+            handler.on_unknown(sentence);
+            return;
+        }
+
+        match self.parse_sentence(sentence) {
+            Ok(msg) => handler::dispatch(handler, &msg),
+            Err(detail) => handler.on_error(ParseError::InvalidMessage(detail)),
+        }
+    }
+
+    /// Locate the next complete sentence, with its optional TAG block
+    fn next_frame<'a>(&self, data: &'a [u8]) -> Frame<'a> {
+        // Find the start of a message, of a TAG block or of a UBX frame
+        let start_pos = if self.skip_ubx {
+            scan::find_any(data, [b'$', b'\\', ubx::SYNC_1])
+        } else {
+            scan::find_any(data, [b'$', b'\\'])
+        };
 
         if start_pos.is_none() {
             // No message start found, consume all spurious data
-            return Ok((None, data.len()));
+            return Frame::Incomplete(data.len());
         }
 
         let start_pos = start_pos.unwrap();
 
+        if data[start_pos] == b'$' {
+            return self.sentence_frame(data, start_pos, None);
+        }
+
+        if data[start_pos] == ubx::SYNC_1 {
+            return match ubx::frame(&data[start_pos..]) {
+                // Skip the whole binary frame, including any '$' in its payload
+                UbxFrame::Complete(len) => Frame::Incomplete(start_pos + len),
+                // A bogus header followed by a complete sentence - resynchronize on it
+                UbxFrame::Partial if completes_sentence(&data[start_pos + 2..]) => {
+                    Frame::Incomplete(start_pos + 1)
+                }
+                // Partial frame - consume spurious data before it, but not the frame itself
+                UbxFrame::Partial => Frame::Incomplete(start_pos),
+                UbxFrame::NotUbx => Frame::Incomplete(start_pos + 1),
+            };
+        }
+
+        // Find the closing backslash of the TAG block, which must come before any line ending
+        let tag_end = scan::find_any(&data[start_pos + 1..], [b'\\', b'\n', b'\r']);
+
+        let tag_end = match tag_end {
+            // Runaway TAG block - drop its backslash and resynchronize on the following data
+            None if data.len() - start_pos > self.max_sentence_len => {
+                return Frame::Incomplete(start_pos + 1)
+            }
+            // Partial TAG block - consume spurious data before it, but not the block itself
+            None => return Frame::Incomplete(start_pos),
+            Some(offset) => start_pos + 1 + offset,
+        };
+
+        if data[tag_end] != b'\\' {
+            // Unterminated TAG block, discard it as spurious data
+            return Frame::Incomplete(tag_end);
+        }
+
+        let sentence_start = tag_end + 1;
+        if sentence_start >= data.len() {
+            // Need the next byte to know whether a sentence follows the block
+            return Frame::Incomplete(start_pos);
+        }
+        if data[sentence_start] != b'$' {
+            // TAG block not followed by a sentence, discard it as spurious data
+            return Frame::Incomplete(sentence_start);
+        }
+
+        match self.sentence_frame(data, sentence_start, Some(&data[start_pos + 1..tag_end])) {
+            // Partial sentence - keep the TAG block for the next call
+            Frame::Incomplete(_) => Frame::Incomplete(start_pos),
+            frame => frame,
+        }
+    }
+
+    /// Locate the end of the sentence starting with '$' at `start_pos`
+    fn sentence_frame<'a>(
+        &self,
+        data: &'a [u8],
+        start_pos: usize,
+        tag: Option<&'a [u8]>,
+    ) -> Frame<'a> {
         // Find the end of the message (either \n or \r)
-        let end_pos = data[start_pos..]
-            .iter()
-            .position(|&b| b == b'\n' || b == b'\r');
+        let end_pos = scan::find_any(&data[start_pos..], [b'\n', b'\r']);
+
+        // Longest sentence without its two-byte line ending
+        let max_len = self.max_sentence_len.saturating_sub(2);
 
         if end_pos.is_none() {
+            if data.len() - start_pos > max_len {
+                // Runaway data - consume it up to the next sentence start
+                return Frame::Overlong(self.resync_point(data, start_pos));
+            }
             // Partial message - consume spurious data before $, but not the partial message
-            return Ok((None, start_pos));
+            return Frame::Incomplete(start_pos);
         }
 
         let end_pos = start_pos + end_pos.unwrap();
-        let sentence = &data[start_pos..end_pos];
 
-        // Parse the complete sentence
-        match self.parse_sentence(sentence) {
-            Some(msg) => {
-                // Successfully parsed - consume up to and including the line ending
-                // Need to skip any additional \r or \n characters
-                let mut consumed = end_pos + 1;
-                while consumed < data.len() && (data[consumed] == b'\r' || data[consumed] == b'\n')
-                {
-                    consumed += 1;
-                }
-                Ok((Some(msg), consumed))
-            }
-            None => {
-                // Complete message but invalid (missing mandatory fields)
-                // Consume the invalid message
-                let mut consumed = end_pos + 1;
-                while consumed < data.len() && (data[consumed] == b'\r' || data[consumed] == b'\n')
-                {
-                    consumed += 1;
-                }
-                Err((ParseError::InvalidMessage, consumed))
-            }
+        // Consume up to and including the line ending,
+        // skipping any additional \r or \n characters
+        let mut consumed = end_pos + 1;
+        while consumed < data.len() && (data[consumed] == b'\r' || data[consumed] == b'\n') {
+            consumed += 1;
+        }
+
+        if end_pos - start_pos > max_len {
+            // Overlong line - resynchronize on a sentence start within it, if any
+            let resync = scan::find(&data[start_pos + 1..end_pos], b'$')
+                .map_or(consumed, |offset| start_pos + 1 + offset);
+            return Frame::Overlong(resync);
         }
+
+        Frame::Sentence {
+            tag,
+            sentence: &data[start_pos..end_pos],
+            consumed,
+        }
+    }
+
+    /// Position of the first sentence start after the overlong one at `start_pos`
+    ///
+    /// Without a further `$` the whole buffer is runaway data.
+    fn resync_point(&self, data: &[u8], start_pos: usize) -> usize {
+        scan::find(&data[start_pos + 1..], b'$').map_or(data.len(), |offset| start_pos + 1 + offset)
+    }
+
+    /// Error reported for a sentence exceeding the maximum length
+    fn overlong_error(&self) -> ParseError {
+        ParseError::InvalidMessage(ErrorDetail::at(
+            ErrorReason::TooLong,
+            self.max_sentence_len.saturating_sub(2),
+        ))
+    }
+
+    /// Verify and decode a complete sentence (without line ending)
+    fn decode(&self, sentence: &[u8]) -> Result<D::Message, ParseError> {
+        if !self.accepts_header(sentence) {
+            return Err(ParseError::Skipped);
+        }
+
+        checksum::verify(sentence, self.checksum_policy).map_err(ParseError::InvalidChecksum)?;
+
+        // Complete message but invalid (missing mandatory fields)
+        let msg = self
+            .parse_sentence(sentence)
+            .map_err(ParseError::InvalidMessage)?;
+        self.decoder.decode_message(msg)
+    }
+
+    /// Check whether sentences from the given talker are decoded
+    ///
+    /// Returns `true` for every talker unless a talker filter was configured
+    /// with `NmeaParserBuilder::accept_talker`.
+    pub fn accepts_talker(&self, talker_id: TalkerId) -> bool {
+        self.talker_filter[0].is_none() || self.talker_filter.contains(&Some(talker_id))
+    }
+
+    /// Check the sentence header against the configured filters
+    ///
+    /// Sentences too short to carry a header are accepted here and rejected
+    /// later by the regular parsing path.
+    fn accepts_header(&self, sentence: &[u8]) -> bool {
+        if (self.message_filter.is_none() && self.talker_filter[0].is_none()) || sentence.len() < 6
+        {
+            return true;
+        }
+
+        let (talker_id, message_type) = self.identify_message(&sentence[1..6]);
+        self.accepts_talker(talker_id) && self.accepts_message_type(message_type)
     }
 
     /// Parse a complete NMEA sentence from a buffer
-    fn parse_sentence(&self, buffer: &[u8]) -> Option<NmeaMessage> {
+    ///
+    /// On failure the error detail locates the offending field, or the address
+    /// field if the sentence type cannot be identified.
+    pub(crate) fn parse_sentence(&self, buffer: &[u8]) -> Result<NmeaMessage, ErrorDetail> {
+        let unknown_type = ErrorDetail::in_field(ErrorReason::UnknownType, 0, 1);
         if buffer.len() < 7 || buffer[0] != b'$' {
-            return None;
+            return Err(unknown_type);
         }
 
         // Find sentence end (before checksum marker '*')
-        let sentence_end = buffer
-            .iter()
-            .position(|&b| b == b'*')
-            .unwrap_or(buffer.len());
+        let sentence_end = scan::find(buffer, b'*').unwrap_or(buffer.len());
 
         if sentence_end < 7 {
-            return None;
+            return Err(unknown_type);
         }
 
+        // Raw sentences are stored in a fixed buffer
+        let raw_error = if buffer.len() > MAX_GENERIC_LEN {
+            ErrorDetail::at(ErrorReason::TooLong, MAX_GENERIC_LEN)
+        } else {
+            unknown_type
+        };
+
         // Extract talker ID and message type
         let (talker_id, message_type) = self.identify_message(&buffer[1..6]);
-        if message_type == MessageType::Unknown {
-            return None;
-        }
-
-        // Parse fields
-        let mut fields = [None; MAX_FIELDS];
-        let mut field_count = 0;
-        let mut field_start = 1; // Skip '$'
-
-        for i in 1..sentence_end {
-            if buffer[i] == b',' || i == sentence_end - 1 {
-                let field_end = if buffer[i] == b',' { i } else { i + 1 };
-
-                if field_count < MAX_FIELDS {
-                    let field_bytes = &buffer[field_start..field_end];
-                    if !field_bytes.is_empty() {
-                        fields[field_count] = Some(Field::from_bytes(field_bytes));
-                    }
-                    field_count += 1;
-                }
-                field_start = i + 1;
+        match message_type {
+            MessageType::Unknown => {
+                return GenericSentence::from_sentence(buffer, talker_id)
+                    .map(NmeaMessage::Unknown)
+                    .ok_or(raw_error)
+            }
+            MessageType::Proprietary => {
+                return ProprietaryData::from_sentence(buffer, talker_id)
+                    .map(NmeaMessage::Proprietary)
+                    .ok_or(raw_error)
             }
+            _ => {}
         }
 
-        let parsed = ParsedSentence {
-            message_type,
-            talker_id,
-            fields,
-            field_count,
-        };
+        let parsed = ParsedSentence::new(message_type, talker_id, &buffer[1..sentence_end]);
 
         // Convert parsed sentence to typed message
         match message_type {
@@ -130,48 +744,282 @@ impl NmeaParser {
             MessageType::GLL => parsed.as_gll().map(NmeaMessage::GLL),
             MessageType::VTG => parsed.as_vtg().map(NmeaMessage::VTG),
             MessageType::GNS => parsed.as_gns().map(NmeaMessage::GNS),
-            MessageType::Unknown => None,
+            MessageType::Query => parsed.as_query().map(NmeaMessage::Query),
+            // Unknown and proprietary types are returned as raw sentences above
+            MessageType::Unknown | MessageType::Proprietary => Err(unknown_type),
         }
     }
 
     /// Identify the talker ID and message type from the sentence header
     fn identify_message(&self, header_bytes: &[u8]) -> (TalkerId, MessageType) {
         if header_bytes.len() < 5 {
-            return (TalkerId::Unknown, MessageType::Unknown);
-        }
-
-        let talker_id = match &header_bytes[0..2] {
-            b"GP" => TalkerId::GP,
-            b"GL" => TalkerId::GL,
-            b"GA" => TalkerId::GA,
-            b"GB" => TalkerId::GB,
-            b"GN" => TalkerId::GN,
-            b"BD" => TalkerId::BD,
-            b"QZ" => TalkerId::QZ,
-            _ => TalkerId::Unknown,
-        };
+            return (TalkerId::from_bytes(header_bytes), MessageType::Unknown);
+        }
+
+        let talker_id = TalkerId::from_bytes(&header_bytes[0..2]);
 
-        let message_type = match &header_bytes[2..5] {
-            b"GGA" => MessageType::GGA,
-            b"RMC" => MessageType::RMC,
-            b"GSA" => MessageType::GSA,
-            b"GSV" => MessageType::GSV,
-            b"GLL" => MessageType::GLL,
-            b"VTG" => MessageType::VTG,
-            b"GNS" => MessageType::GNS,
-            _ => MessageType::Unknown,
+        // Proprietary sentences carry a vendor mnemonic instead of a talker, and
+        // query sentences the addressed listener instead of a formatter
+        let message_type = if header_bytes[0] == b'P' {
+            MessageType::Proprietary
+        } else if header_bytes[4] == b'Q' {
+            MessageType::Query
+        } else {
+            MessageType::from_formatter(&header_bytes[2..5])
         };
 
         (talker_id, message_type)
     }
 }
 
+/// Check whether `data` holds a `$` followed by a line ending
+///
+/// A partial UBX frame is only retained until a complete sentence follows
+/// it: a bogus sync header claiming a long payload would otherwise hold back
+/// every following sentence until the claimed length is buffered.
+fn completes_sentence(data: &[u8]) -> bool {
+    scan::find(data, b'$')
+        .is_some_and(|start| scan::find_any(&data[start..], [b'\n', b'\r']).is_some())
+}
+
+/// Minimum number of bytes completing the partial sentence left in `partial`
+///
+/// Only derivable once the checksum marker was received: the remaining
+/// checksum digits plus one line-ending byte.
+fn min_needed(partial: &[u8]) -> Option<usize> {
+    if partial.first() == Some(&ubx::SYNC_1) {
+        return None;
+    }
+    let start = scan::find(partial, b'$')?;
+    let star = scan::find(&partial[start..], b'*')?;
+    let digits = partial.len() - start - star - 1;
+    Some(3usize.saturating_sub(digits).max(1))
+}
+
 impl Default for NmeaParser {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// Builder collecting the configuration options of a `NmeaParser`
+///
+/// Options not set on the builder keep the defaults used by `NmeaParser::new()`.
+#[derive(Debug, Clone)]
+pub struct NmeaParserBuilder<D = NoDecoder> {
+    checksum_policy: ChecksumPolicy,
+    message_filter: Option<u16>,
+    talker_filter: [Option<TalkerId>; MAX_ACCEPTED_TALKERS],
+    max_sentence_len: usize,
+    skip_ubx: bool,
+    decoder: D,
+}
+
+impl NmeaParserBuilder {
+    /// Create a builder with the default configuration
+    pub fn new() -> Self {
+        NmeaParserBuilder {
+            checksum_policy: ChecksumPolicy::default(),
+            message_filter: None,
+            talker_filter: [None; MAX_ACCEPTED_TALKERS],
+            max_sentence_len: DEFAULT_MAX_SENTENCE_LEN,
+            skip_ubx: true,
+            decoder: NoDecoder,
+        }
+    }
+}
+
+impl<D> NmeaParserBuilder<D> {
+    /// Set the maximum sentence length, including `$` and the line ending
+    /// (default `DEFAULT_MAX_SENTENCE_LEN`, 82)
+    ///
+    /// Longer sentences are consumed and reported as `ParseError::InvalidMessage`
+    /// with `ErrorReason::TooLong`. Data without a line ending is consumed up to
+    /// the next `$` as soon as it exceeds the limit, instead of being kept as a
+    /// partial sentence.
+    ///
+    /// Raising the limit lets longer sentences through framing and checksum
+    /// verification, but unknown and proprietary sentences are still copied
+    /// into an 80-byte `GenericSentence` and reported as `ErrorReason::TooLong`
+    /// beyond it when decoded; `NmeaParser::parse_bytes_ref` returns them
+    /// borrowed from the input instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustedbytes_nmea::{ErrorReason, NmeaParser};
+    ///
+    /// let parser = NmeaParser::builder().max_sentence_length(32).build();
+    ///
+    /// let data = b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+    /// let (err, consumed) = parser.parse_bytes(data).unwrap_err();
+    /// assert_eq!(err.reason(), Some(ErrorReason::TooLong));
+    /// assert_eq!(consumed, data.len());
+    /// ```
+    pub fn max_sentence_length(mut self, len: usize) -> Self {
+        self.max_sentence_len = len;
+        self
+    }
+
+    /// Skip interleaved u-blox UBX binary frames as a whole (default `true`)
+    ///
+    /// A frame starting with the `0xB5 0x62` sync characters is consumed as
+    /// spurious data once it is complete and its checksum matches, so a `$`
+    /// inside its payload is not taken for a sentence start. A partial frame
+    /// is kept until more data arrives, unless a complete sentence already
+    /// follows it: the sync characters are then dropped as noise. When
+    /// disabled, binary data is skipped byte by byte up to the next `$`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustedbytes_nmea::{MessageType, NmeaParser};
+    ///
+    /// let parser = NmeaParser::new();
+    ///
+    /// // UBX frame with a '$' in its payload, followed by a sentence
+    /// let data = b"\xB5\x62\x06\x01\x02\x00$\x01\x2E\x7A$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+    ///
+    /// let (msg, consumed) = parser.parse_bytes(data).unwrap();
+    /// assert!(msg.is_none());
+    /// assert_eq!(consumed, 10);
+    ///
+    /// let (msg, _) = parser.parse_bytes(&data[consumed..]).unwrap();
+    /// assert_eq!(msg.unwrap().message_type(), MessageType::GLL);
+    /// ```
+    pub fn skip_ubx_frames(mut self, enabled: bool) -> Self {
+        self.skip_ubx = enabled;
+        self
+    }
+
+    /// Set the checksum verification policy (default `ChecksumPolicy::Lenient`)
+    pub fn checksum_policy(mut self, policy: ChecksumPolicy) -> Self {
+        self.checksum_policy = policy;
+        self
+    }
+
+    /// Decode only the registered message types
+    ///
+    /// May be called several times to register more types. Once at least one
+    /// type is registered, sentences of any other type are consumed after
+    /// header identification and reported as `ParseError::Skipped`, without
+    /// checksum verification or field extraction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustedbytes_nmea::{MessageType, NmeaParser, ParseError};
+    ///
+    /// let parser = NmeaParser::builder()
+    ///     .accept_message_type(MessageType::GGA)
+    ///     .accept_message_type(MessageType::RMC)
+    ///     .build();
+    ///
+    /// let result = parser.parse_bytes(b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n");
+    /// assert!(matches!(result, Err((ParseError::Skipped, _))));
+    /// ```
+    pub fn accept_message_type(mut self, message_type: MessageType) -> Self {
+        self.message_filter = Some(self.message_filter.unwrap_or(0) | message_type.mask());
+        self
+    }
+
+    /// Decode only sentences from the registered talkers
+    ///
+    /// May be called up to `MAX_ACCEPTED_TALKERS` times to register more
+    /// talkers; further talkers are ignored. Once at least one talker is
+    /// registered, sentences from any other talker are consumed and reported
+    /// as `ParseError::Skipped`, without checksum verification or field
+    /// extraction.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustedbytes_nmea::{NmeaParser, ParseError, TalkerId};
+    ///
+    /// let parser = NmeaParser::builder().accept_talker(TalkerId::GN).build();
+    ///
+    /// let result = parser.parse_bytes(b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n");
+    /// assert!(matches!(result, Err((ParseError::Skipped, _))));
+    /// ```
+    pub fn accept_talker(mut self, talker_id: TalkerId) -> Self {
+        if let Some(slot) = self
+            .talker_filter
+            .iter_mut()
+            .find(|slot| slot.is_none() || **slot == Some(talker_id))
+        {
+            *slot = Some(talker_id);
+        }
+        self
+    }
+
+    /// Offer sentences not decoded by the library to a custom `SentenceDecoder`
+    ///
+    /// The built parser returns `Decoded` messages from `parse_bytes` and the
+    /// other `parse_bytes_*` methods, `iter`, `parse_all`, and the
+    /// `StreamParser` and readers wrapping it:
+    /// - `Decoded::Custom(output)` - Sentence accepted and decoded by `decoder`
+    /// - `Decoded::Builtin(message)` - Sentence decoded by the library, or
+    ///   `NmeaMessage::Unknown`/`NmeaMessage::Proprietary` if `decoder` does
+    ///   not accept it
+    ///
+    /// A sentence accepted but not decoded by `decoder` is reported as
+    /// `ParseError::InvalidMessage` with `ErrorReason::DecoderFailed`.
+    /// `parse_into` keeps dispatching built-in messages to its handler.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustedbytes_nmea::{GenericSentence, NmeaParser, SentenceDecoder};
+    ///
+    /// /// Decoder for MTW (water temperature) sentences
+    /// struct WaterTemperature;
+    ///
+    /// impl SentenceDecoder for WaterTemperature {
+    ///     type Output = f32;
+    ///
+    ///     fn accepts(&self, sentence: &GenericSentence) -> bool {
+    ///         sentence.sentence_formatter() == "MTW"
+    ///     }
+    ///
+    ///     fn decode(&self, sentence: &GenericSentence) -> Option<f32> {
+    ///         sentence.field(0)?.parse().ok()
+    ///     }
+    /// }
+    ///
+    /// let parser = NmeaParser::builder().decoder(WaterTemperature).build();
+    /// let (decoded, _consumed) = parser.parse_bytes(b"$IIMTW,18.5,C\r\n").unwrap();
+    /// assert_eq!(decoded.unwrap().as_custom(), Some(&18.5));
+    /// ```
+    pub fn decoder<E: SentenceDecoder>(self, decoder: E) -> NmeaParserBuilder<E> {
+        NmeaParserBuilder {
+            checksum_policy: self.checksum_policy,
+            message_filter: self.message_filter,
+            talker_filter: self.talker_filter,
+            max_sentence_len: self.max_sentence_len,
+            skip_ubx: self.skip_ubx,
+            decoder,
+        }
+    }
+
+    /// Build the configured parser
+    pub fn build(self) -> NmeaParser<D> {
+        NmeaParser {
+            checksum_policy: self.checksum_policy,
+            message_filter: self.message_filter,
+            talker_filter: self.talker_filter,
+            max_sentence_len: self.max_sentence_len,
+            skip_ubx: self.skip_ubx,
+            decoder: self.decoder,
+        }
+    }
+}
+
+impl Default for NmeaParserBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 impl NmeaParser {
     /// Parse a complete sentence with line ending for testing purposes
@@ -187,6 +1035,8 @@ impl NmeaParser {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::datetime::NmeaTime;
+    use crate::types::{FixType, Status};
 
     // Tests based on references/nmea_valid.txt
     #[test]
@@ -204,7 +1054,15 @@ mod tests {
         assert_eq!(msg.message_type(), MessageType::GGA);
 
         let gga = msg.as_gga().expect("Should parse as GGA");
-        assert_eq!(gga.time(), "123519");
+        assert_eq!(
+            gga.time(),
+            NmeaTime {
+                hour: 12,
+                minute: 35,
+                second: 19,
+                millis: 0
+            }
+        );
         assert_eq!(gga.latitude, 4807.038);
         assert_eq!(gga.lat_direction, 'N');
         assert_eq!(gga.longitude, 1131.000);
@@ -214,7 +1072,7 @@ mod tests {
     #[test]
     fn test_valid_rmc_from_reference() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPRMC,235947,A,5540.123,N,01231.456,E,000.0,360.0,130694,011.3,E*62\r\n";
+        let sentence = b"$GPRMC,235947,A,5540.123,N,01231.456,E,000.0,360.0,130694,011.3,E*75\r\n";
 
         let result = parser.parse_bytes(sentence);
         assert!(result.is_ok());
@@ -226,8 +1084,16 @@ mod tests {
         assert_eq!(msg.message_type(), MessageType::RMC);
 
         let rmc = msg.as_rmc().expect("Should parse as RMC");
-        assert_eq!(rmc.time(), "235947");
-        assert_eq!(rmc.status, 'A');
+        assert_eq!(
+            rmc.time(),
+            NmeaTime {
+                hour: 23,
+                minute: 59,
+                second: 47,
+                millis: 0
+            }
+        );
+        assert_eq!(rmc.status, Status::Valid);
         assert_eq!(rmc.latitude, 5540.123);
         assert_eq!(rmc.lat_direction, 'N');
     }
@@ -235,7 +1101,7 @@ mod tests {
     #[test]
     fn test_valid_gsa_from_reference() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGSA,A,3,04,05,09,12,24,25,29,31,,,,,1.8,1.0,1.5*33\r\n";
+        let sentence = b"$GPGSA,A,3,04,05,09,12,24,25,29,31,,,,,1.8,1.0,1.5*3D\r\n";
 
         let result = parser.parse_bytes(sentence);
         assert!(result.is_ok());
@@ -248,13 +1114,13 @@ mod tests {
 
         let gsa = msg.as_gsa().expect("Should parse as GSA");
         assert_eq!(gsa.mode, 'A');
-        assert_eq!(gsa.fix_type, 3);
+        assert_eq!(gsa.fix_type, FixType::Fix3D);
     }
 
     #[test]
     fn test_valid_gsv_from_reference() {
         let parser = NmeaParser::new();
-        let sentence = b"$GPGSV,3,1,12,02,17,315,44,04,77,268,47,05,55,147,45,07,32,195,42*70\r\n";
+        let sentence = b"$GPGSV,3,1,12,02,17,315,44,04,77,268,47,05,55,147,45,07,32,195,42*7B\r\n";
 
         let result = parser.parse_bytes(sentence);
         assert!(result.is_ok());
@@ -271,24 +1137,120 @@ mod tests {
         assert_eq!(gsv.satellites_in_view, 12);
     }
 
+    #[test]
+    fn test_valid_gns_through_parse_bytes() {
+        let parser = NmeaParser::new();
+        let sentence =
+            b"$GNGNS,122310.0,3723.46587,N,12202.26957,W,AAAN,12,0.9,1005.543,6.5,,,V*02\r\n";
+
+        let result = parser.parse_bytes(sentence);
+        assert!(result.is_ok());
+        let (msg, consumed) = result.unwrap();
+        assert!(msg.is_some());
+        assert_eq!(consumed, sentence.len());
+
+        let msg = msg.unwrap();
+        assert_eq!(msg.message_type(), MessageType::GNS);
+        assert_eq!(msg.talker_id(), TalkerId::GN);
+
+        match msg {
+            NmeaMessage::GNS(gns) => {
+                assert_eq!(
+                    gns.time(),
+                    NmeaTime {
+                        hour: 12,
+                        minute: 23,
+                        second: 10,
+                        millis: 0
+                    }
+                );
+                assert_eq!(gns.mode_indicator(), "AAAN");
+                assert_eq!(gns.num_satellites, 12);
+                assert_eq!(gns.nav_status, Some('V'));
+            }
+            _ => panic!("Expected GNS message"),
+        }
+    }
+
+    #[test]
+    fn test_instrument_talker_ids() {
+        let parser = NmeaParser::new();
+
+        let vtg = b"$IIVTG,054.7,T,034.4,M,005.5,N,010.2,K*5F\r\n";
+        let (msg, _) = parser.parse_bytes(vtg).unwrap();
+        let msg = msg.unwrap();
+        assert_eq!(msg.talker_id(), TalkerId::II);
+        assert!(!msg.talker_id().is_gnss());
+
+        let rmc = b"$INRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*7A\r\n";
+        let (msg, _) = parser.parse_bytes(rmc).unwrap();
+        assert_eq!(msg.unwrap().talker_id(), TalkerId::IN);
+
+        let gsa = b"$GIGSA,A,3,02,03,,,,,,,,,,,1.8,1.0,1.5,6*3C\r\n";
+        let (msg, _) = parser.parse_bytes(gsa).unwrap();
+        let msg = msg.unwrap();
+        assert_eq!(msg.talker_id(), TalkerId::GI);
+        assert!(msg.talker_id().is_gnss());
+    }
+
+    #[test]
+    fn test_unknown_talker_keeps_raw_bytes() {
+        let parser = NmeaParser::new();
+        let sentence = b"$XXGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*50\r\n";
+
+        let (msg, consumed) = parser.parse_bytes(sentence).unwrap();
+        assert_eq!(consumed, sentence.len());
+
+        let talker_id = msg.unwrap().talker_id();
+        assert_eq!(talker_id, TalkerId::Unknown(*b"XX"));
+        assert_eq!(talker_id.as_str(), "XX");
+    }
+
     // Tests based on references/nmea_edge_cases.txt
     #[test]
     fn test_edge_case_gga_empty_fields() {
         let parser = NmeaParser::new();
         // GGA with all empty fields (should fail - mandatory fields missing)
-        let sentence = b"$GPGGA,123519,,,,,,0,00,99.99,,,,,,*48\r\n";
+        let sentence = b"$GPGGA,123519,,,,,,0,00,99.99,,,,,,*69\r\n";
 
         let result = parser.parse_bytes(sentence);
         assert!(result.is_err());
         let (err, _consumed) = result.unwrap_err();
-        assert_eq!(err, ParseError::InvalidMessage);
+        assert!(matches!(err, ParseError::InvalidMessage(_)));
+    }
+
+    #[test]
+    fn test_error_detail_locates_field() {
+        let parser = NmeaParser::new();
+
+        let (err, _) = parser
+            .parse_bytes(b"$GPGGA,123519,,,,,,0,00,99.99,,,,,,*69\r\n")
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::InvalidMessage(ErrorDetail::in_field(ErrorReason::MissingField, 2, 14))
+        );
+
+        let (err, _) = parser
+            .parse_bytes(b"$GPGGA,123519,4807.038,N,011x1.000,E,1,08,0.9,545.4,M,46.9,M,,*0C\r\n")
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::InvalidMessage(ErrorDetail::in_field(ErrorReason::InvalidNumber, 4, 25))
+        );
+
+        let (err, _) = parser.parse_bytes(b"$X,1*45\r\n").unwrap_err();
+        assert_eq!(err.reason(), Some(ErrorReason::UnknownType));
+        assert_eq!(err.detail().unwrap().field, Some(0));
+
+        assert_eq!(ParseError::Skipped.detail(), None);
     }
 
     #[test]
     fn test_edge_case_rmc_zero_coordinates() {
         let parser = NmeaParser::new();
         // RMC with zero coordinates (valid but unusual)
-        let sentence = b"$GPRMC,000000,A,0000.000,N,00000.000,E,000.0,000.0,000000,000.0,W*7C\r\n";
+        let sentence = b"$GPRMC,000000,A,0000.000,N,00000.000,E,000.0,000.0,000000,000.0,W*64\r\n";
 
         let result = parser.parse_bytes(sentence);
         assert!(result.is_ok());
@@ -321,60 +1283,224 @@ mod tests {
     #[test]
     fn test_edge_case_concatenated_messages() {
         let parser = NmeaParser::new();
-        // Two messages concatenated - parser will parse up to the first checksum
-        // and treat the rest as part of the same line
-        let data = b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47$GPRMC,235947,A,5540.123,N,01231.456,E,000.0,360.0,130694,011.3,E*62\r\n";
+        // Two messages concatenated without line ending - the line exceeds the
+        // maximum sentence length
+        let data = b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47$GPRMC,235947,A,5540.123,N,01231.456,E,000.0,360.0,130694,011.3,E*75\r\n";
 
         let result = parser.parse_bytes(data);
-        // The parser finds the first * and parses up to that point as the first message
-        // This successfully parses the GGA message
-        assert!(result.is_ok());
-        let (msg, consumed) = result.unwrap();
-        assert!(msg.is_some());
-        let msg = msg.unwrap();
-        assert_eq!(msg.message_type(), MessageType::GGA);
-        assert_eq!(consumed, data.len());
+        // The first sentence lost its line ending and is rejected, parsing
+        // resumes at the second sentence start
+        assert!(result.is_err());
+        let (err, consumed) = result.unwrap_err();
+        assert_eq!(err.reason(), Some(ErrorReason::TooLong));
+        assert_eq!(consumed, 65);
+
+        let (msg, consumed_rmc) = parser.parse_bytes(&data[consumed..]).unwrap();
+        assert_eq!(msg.unwrap().message_type(), MessageType::RMC);
+        assert_eq!(consumed + consumed_rmc, data.len());
     }
 
     #[test]
     fn test_edge_case_unsupported_message_types() {
         let parser = NmeaParser::new();
 
-        // GPTXT - text message (not supported)
-        let txt_sentence = b"$GPTXT,01,01,02,Software Version 7.03.00 (12345)*6E\r\n";
+        // GPTXT - text message (not decoded, kept as generic sentence)
+        let txt_sentence = b"$GPTXT,01,01,02,Software Version 7.03.00 (12345)*1C\r\n";
         let result = parser.parse_bytes(txt_sentence);
-        assert!(result.is_err());
-        let (err, _consumed) = result.unwrap_err();
-        assert_eq!(err, ParseError::InvalidMessage);
+        assert!(result.is_ok());
+        let (msg, consumed) = result.unwrap();
+        assert_eq!(consumed, txt_sentence.len());
+        let msg = msg.unwrap();
+        assert_eq!(msg.message_type(), MessageType::Unknown);
+        assert_eq!(msg.as_unknown().unwrap().sentence_formatter(), "TXT");
 
-        // GPXTE - cross-track error (not supported)
+        // GPXTE - cross-track error (not decoded, kept as generic sentence)
         let xte_sentence = b"$GPXTE,A,A,0.67,L,N*6F\r\n";
         let result2 = parser.parse_bytes(xte_sentence);
-        assert!(result2.is_err());
-        let (err2, _consumed2) = result2.unwrap_err();
-        assert_eq!(err2, ParseError::InvalidMessage);
+        assert!(result2.is_ok());
+        let (msg2, _consumed2) = result2.unwrap();
+        let msg2 = msg2.unwrap();
+        assert_eq!(msg2.as_unknown().unwrap().field(2), Some("0.67"));
     }
 
-    // Tests based on references/nmea_invalid.txt
     #[test]
     fn test_invalid_wrong_checksum() {
         let parser = NmeaParser::new();
         // Valid GGA structure but wrong checksum (*00 instead of *47)
         let sentence = b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*00\r\n";
 
-        // Parser doesn't validate checksum, so this will parse successfully
         let result = parser.parse_bytes(sentence);
-        assert!(result.is_ok());
-        let (msg, consumed) = result.unwrap();
+        assert!(result.is_err());
+        let (err, consumed) = result.unwrap_err();
+        assert!(matches!(err, ParseError::InvalidChecksum(_)));
+        assert_eq!(consumed, sentence.len());
+    }
+
+    #[test]
+    fn test_invalid_malformed_checksum() {
+        let parser = NmeaParser::new();
+        // Checksum marker followed by a single digit
+        let sentence = b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1\r\n";
+
+        let (err, consumed) = parser.parse_bytes(sentence).unwrap_err();
+        assert!(matches!(err, ParseError::InvalidChecksum(_)));
+        assert_eq!(consumed, sentence.len());
+    }
+
+    #[test]
+    fn test_invalid_checksum_then_valid_message() {
+        let parser = NmeaParser::new();
+        let data = b"$GPGLL,4916.45,N,12311.12,W,225444,A,*00\r\n$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+
+        let (err, consumed) = parser.parse_bytes(data).unwrap_err();
+        assert!(matches!(err, ParseError::InvalidChecksum(_)));
+
+        let (msg, _) = parser.parse_bytes(&data[consumed..]).unwrap();
+        assert_eq!(msg.unwrap().message_type(), MessageType::GLL);
+    }
+
+    #[test]
+    fn test_checksum_policy_strict() {
+        let parser = NmeaParser::new().with_checksum_policy(ChecksumPolicy::Strict);
+        assert_eq!(parser.checksum_policy(), ChecksumPolicy::Strict);
+
+        let sentence = b"$GPGLL,4916.45,N,12311.12,W,225444,A,\r\n";
+        let (err, consumed) = parser.parse_bytes(sentence).unwrap_err();
+        assert!(matches!(err, ParseError::InvalidChecksum(_)));
+        assert_eq!(consumed, sentence.len());
+
+        let sentence = b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+        let (msg, _) = parser.parse_bytes(sentence).unwrap();
         assert!(msg.is_some());
+    }
+
+    #[test]
+    fn test_builder_configuration() {
+        let parser = NmeaParser::builder().build();
+        assert_eq!(parser.checksum_policy(), ChecksumPolicy::Lenient);
+
+        let parser = NmeaParser::builder()
+            .checksum_policy(ChecksumPolicy::Strict)
+            .build();
+        assert_eq!(parser.checksum_policy(), ChecksumPolicy::Strict);
+
+        let sentence = b"$GPGLL,4916.45,N,12311.12,W,225444,A,\r\n";
+        let (err, _) = parser.parse_bytes(sentence).unwrap_err();
+        assert!(matches!(err, ParseError::InvalidChecksum(_)));
+    }
+
+    #[test]
+    fn test_message_type_filter() {
+        let parser = NmeaParser::builder()
+            .accept_message_type(MessageType::GGA)
+            .build();
+        assert!(parser.accepts_message_type(MessageType::GGA));
+        assert!(!parser.accepts_message_type(MessageType::GSV));
+
+        let gsv = b"$GPGSV,2,1,08,01,40,083,46,02,17,308,41,12,07,344,39,14,22,228,45*75\r\n";
+        let (err, consumed) = parser.parse_bytes(gsv).unwrap_err();
+        assert_eq!(err, ParseError::Skipped);
+        assert_eq!(consumed, gsv.len());
+
+        // Skipped sentences are not checksum-verified
+        let bad_gsv = b"$GPGSV,2,1,08,01,40,083,46*00\r\n";
+        let (err, _) = parser.parse_bytes(bad_gsv).unwrap_err();
+        assert_eq!(err, ParseError::Skipped);
+
+        let gga = b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n";
+        let (msg, consumed) = parser.parse_bytes(gga).unwrap();
+        assert_eq!(msg.unwrap().message_type(), MessageType::GGA);
+        assert_eq!(consumed, gga.len());
+    }
+
+    #[test]
+    fn test_talker_filter() {
+        let parser = NmeaParser::builder().accept_talker(TalkerId::GN).build();
+        assert!(parser.accepts_talker(TalkerId::GN));
+        assert!(!parser.accepts_talker(TalkerId::GP));
+
+        let gp = b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n";
+        let (err, consumed) = parser.parse_bytes(gp).unwrap_err();
+        assert_eq!(err, ParseError::Skipped);
+        assert_eq!(consumed, gp.len());
+
+        let gn = b"$GNGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*59\r\n";
+        let (msg, _) = parser.parse_bytes(gn).unwrap();
+        assert_eq!(msg.unwrap().talker_id(), TalkerId::GN);
+    }
+
+    #[test]
+    fn test_talker_and_message_type_filters_combined() {
+        let parser = NmeaParser::builder()
+            .accept_talker(TalkerId::GN)
+            .accept_talker(TalkerId::Unknown(*b"XX"))
+            .accept_message_type(MessageType::GLL)
+            .build();
+        assert!(parser.accepts_talker(TalkerId::Unknown(*b"XX")));
+
+        let gn_gga = b"$GNGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*59\r\n";
+        let (err, _) = parser.parse_bytes(gn_gga).unwrap_err();
+        assert_eq!(err, ParseError::Skipped);
+
+        let gn_gll = b"$GNGLL,4916.45,N,12311.12,W,225444,A,*03\r\n";
+        let (msg, _) = parser.parse_bytes(gn_gll).unwrap();
+        assert!(msg.is_some());
+    }
+
+    #[test]
+    fn test_talker_filter_capacity() {
+        let mut builder = NmeaParser::builder();
+        for talker in [
+            TalkerId::GP,
+            TalkerId::GL,
+            TalkerId::GA,
+            TalkerId::GB,
+            TalkerId::GN,
+            TalkerId::BD,
+            TalkerId::QZ,
+            TalkerId::GQ,
+            TalkerId::GI,
+        ] {
+            builder = builder.accept_talker(talker);
+        }
+        // Registering a talker twice does not use another slot
+        let parser = builder.accept_talker(TalkerId::GP).build();
+
+        assert!(parser.accepts_talker(TalkerId::GQ));
+        assert!(!parser.accepts_talker(TalkerId::GI));
+    }
+
+    #[test]
+    fn test_no_message_type_filter_accepts_all() {
+        let parser = NmeaParser::new();
+        assert!(parser.accepts_message_type(MessageType::GSV));
+        assert!(parser.accepts_message_type(MessageType::Unknown));
+    }
+
+    #[test]
+    fn test_checksum_policy_off() {
+        let parser = NmeaParser::new().with_checksum_policy(ChecksumPolicy::Off);
+        let sentence = b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*00\r\n";
+
+        let (msg, consumed) = parser.parse_bytes(sentence).unwrap();
+        assert_eq!(msg.unwrap().message_type(), MessageType::GGA);
         assert_eq!(consumed, sentence.len());
     }
 
+    #[test]
+    fn test_lowercase_checksum() {
+        let parser = NmeaParser::new();
+        let sentence = b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1d\r\n";
+
+        let (msg, _) = parser.parse_bytes(sentence).unwrap();
+        assert!(msg.is_some());
+    }
+
     #[test]
     fn test_invalid_rmc_void_status() {
         let parser = NmeaParser::new();
         // RMC with status 'V' (void/invalid) - still valid structure
-        let sentence = b"$GPRMC,235947,V,5540.123,N,01231.456,E,000.0,360.0,130694,011.3,E*00\r\n";
+        let sentence = b"$GPRMC,235947,V,5540.123,N,01231.456,E,000.0,360.0,130694,011.3,E*62\r\n";
 
         let result = parser.parse_bytes(sentence);
         assert!(result.is_ok());
@@ -384,7 +1510,7 @@ mod tests {
 
         let msg = msg.unwrap();
         let rmc = msg.as_rmc().expect("Should parse as RMC");
-        assert_eq!(rmc.status, 'V'); // Status V means data is invalid but structure is valid
+        assert_eq!(rmc.status, Status::Invalid); // Status V means data is invalid but structure is valid
     }
 
     #[test]
@@ -428,7 +1554,7 @@ mod tests {
         assert_eq!(consumed1, gga.len());
 
         // Parse second message
-        let rmc = b"$GPRMC,235947,A,5540.123,N,01231.456,E,000.0,360.0,130694,011.3,E*62\r\n";
+        let rmc = b"$GPRMC,235947,A,5540.123,N,01231.456,E,000.0,360.0,130694,011.3,E*75\r\n";
         let result2 = parser.parse_bytes(rmc);
         assert!(result2.is_ok());
         let (msg2, consumed2) = result2.unwrap();
@@ -436,11 +1562,289 @@ mod tests {
         assert_eq!(consumed2, rmc.len());
 
         // Parse third message
-        let gsa = b"$GPGSA,A,3,04,05,09,12,24,25,29,31,,,,,1.8,1.0,1.5*33\r\n";
+        let gsa = b"$GPGSA,A,3,04,05,09,12,24,25,29,31,,,,,1.8,1.0,1.5*3D\r\n";
         let result3 = parser.parse_bytes(gsa);
         assert!(result3.is_ok());
         let (msg3, consumed3) = result3.unwrap();
         assert!(msg3.is_some());
         assert_eq!(consumed3, gsa.len());
     }
+
+    #[test]
+    fn test_parse_all_into_slice() {
+        let parser = NmeaParser::new();
+        let data = b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n\
+                     $GPGLL,4916.45,N,12311.12,W,225444,A,*00\r\n\
+                     $GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n\
+                     $GPGGA,1235";
+
+        let mut out: [Option<NmeaMessage>; 4] = Default::default();
+        let (count, consumed) = parser.parse_all(data, &mut out);
+
+        // The message with a wrong checksum is discarded
+        assert_eq!(count, 2);
+        assert_eq!(out[0].as_ref().unwrap().message_type(), MessageType::GGA);
+        assert_eq!(out[1].as_ref().unwrap().message_type(), MessageType::GLL);
+        assert!(out[2].is_none());
+        assert_eq!(&data[consumed..], b"$GPGGA,1235");
+    }
+
+    #[test]
+    fn test_parse_all_stops_when_output_full() {
+        let parser = NmeaParser::new();
+        let gll = b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+        let mut data = [0u8; 126];
+        for chunk in data.chunks_mut(gll.len()) {
+            chunk.copy_from_slice(gll);
+        }
+
+        let mut out: [Option<NmeaMessage>; 2] = Default::default();
+        let (count, consumed) = parser.parse_all(&data, &mut out);
+
+        assert_eq!(count, 2);
+        assert_eq!(consumed, 2 * gll.len());
+
+        let (count, consumed) = parser.parse_all(&data, &mut []);
+        assert_eq!((count, consumed), (0, 0));
+    }
+
+    #[test]
+    fn test_tag_block_before_sentence() {
+        let parser = NmeaParser::new();
+        let data = b"\\s:r3669961,c:1120959341*7B\\$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+
+        let result = parser.parse_bytes_tagged(data);
+        assert!(result.is_ok());
+        let (msg, tag, consumed) = result.unwrap();
+        assert_eq!(consumed, data.len());
+        assert_eq!(msg.unwrap().message_type(), MessageType::GLL);
+
+        let tag = tag.expect("Should parse TAG block");
+        assert_eq!(tag.source(), Some("r3669961"));
+        assert_eq!(tag.timestamp, Some(1120959341));
+
+        // parse_bytes accepts the same input and drops the TAG block
+        let (msg, consumed) = parser.parse_bytes(data).unwrap();
+        assert!(msg.is_some());
+        assert_eq!(consumed, data.len());
+    }
+
+    #[test]
+    fn test_tag_block_invalid_checksum_still_parses_sentence() {
+        let parser = NmeaParser::new();
+        let data = b"\\s:r3669961,c:1120959341*00\\$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+
+        let (msg, tag, consumed) = parser.parse_bytes_tagged(data).unwrap();
+        assert!(msg.is_some());
+        assert!(tag.is_none());
+        assert_eq!(consumed, data.len());
+    }
+
+    #[test]
+    fn test_tag_block_partial() {
+        let parser = NmeaParser::new();
+
+        // Partial TAG block after spurious data
+        let data = b"xx\\s:r3669961,c:11";
+        let (msg, tag, consumed) = parser.parse_bytes_tagged(data).unwrap();
+        assert!(msg.is_none());
+        assert!(tag.is_none());
+        assert_eq!(consumed, 2);
+
+        // Complete TAG block but partial sentence
+        let data = b"\\s:src\\$GPGLL,4916.45,N";
+        let (msg, tag, consumed) = parser.parse_bytes_tagged(data).unwrap();
+        assert!(msg.is_none());
+        assert!(tag.is_none());
+        assert_eq!(consumed, 0);
+    }
+
+    #[test]
+    fn test_tag_block_without_sentence_is_spurious() {
+        let parser = NmeaParser::new();
+        let data = b"\\s:src\\garbage\r\n";
+
+        let (msg, tag, consumed) = parser.parse_bytes_tagged(data).unwrap();
+        assert!(msg.is_none());
+        assert!(tag.is_none());
+        assert_eq!(consumed, 7);
+    }
+
+    #[test]
+    fn test_hinted_partial_sentence() {
+        let parser = NmeaParser::new();
+
+        // No checksum marker yet, the missing length is unknown
+        let (msg, hint) = parser.parse_bytes_hinted(b"xx$GPGLL,4916.45,N").unwrap();
+        assert!(msg.is_none());
+        assert_eq!(hint.discard, 2);
+        assert_eq!(hint.min_needed, None);
+
+        let (_, hint) = parser
+            .parse_bytes_hinted(b"$GPGLL,4916.45,N,12311.12,W,225444,A,*")
+            .unwrap();
+        assert_eq!(hint.discard, 0);
+        assert_eq!(hint.min_needed, Some(3));
+
+        let (_, hint) = parser
+            .parse_bytes_hinted(b"\\s:src\\$GPGLL,4916.45,N,12311.12,W,225444,A,*1D")
+            .unwrap();
+        assert_eq!(hint.discard, 0);
+        assert_eq!(hint.min_needed, Some(1));
+
+        // Only spurious data
+        let (_, hint) = parser.parse_bytes_hinted(b"garbage").unwrap();
+        assert_eq!(hint.discard, 7);
+        assert_eq!(hint.min_needed, None);
+    }
+
+    #[test]
+    fn test_hinted_complete_sentence() {
+        let parser = NmeaParser::new();
+        let data = b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n$GPGLL";
+
+        let (msg, hint) = parser.parse_bytes_hinted(data).unwrap();
+        assert!(msg.is_some());
+        assert_eq!(hint.discard, 42);
+        assert_eq!(hint.min_needed, None);
+
+        let data = b"$GPGLL,4916.45,N,12311.12,W,225444,A,*00\r\n";
+        let (err, consumed) = parser.parse_bytes_hinted(data).unwrap_err();
+        assert!(matches!(err, ParseError::InvalidChecksum(_)));
+        assert_eq!(consumed, data.len());
+    }
+
+    #[test]
+    fn test_runaway_data_is_consumed() {
+        let parser = NmeaParser::new();
+
+        // Binary noise after a '$' never gets a line ending
+        let mut data = [0xA5u8; 120];
+        data[3] = b'$';
+        let (err, consumed) = parser.parse_bytes(&data).unwrap_err();
+        assert_eq!(err.reason(), Some(ErrorReason::TooLong));
+        assert_eq!(consumed, data.len());
+
+        // Resynchronize on a later sentence start
+        data[100] = b'$';
+        let (_, consumed) = parser.parse_bytes(&data).unwrap_err();
+        assert_eq!(consumed, 100);
+
+        // A partial sentence within the limit keeps waiting
+        let (msg, consumed) = parser.parse_bytes(&data[..83]).unwrap();
+        assert!(msg.is_none());
+        assert_eq!(consumed, 3);
+    }
+
+    #[test]
+    fn test_max_sentence_length_configurable() {
+        let data = b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n";
+
+        let parser = NmeaParser::builder().max_sentence_length(48).build();
+        assert_eq!(parser.max_sentence_length(), 48);
+        let (err, consumed) = parser.parse_bytes(data).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::InvalidMessage(ErrorDetail::at(ErrorReason::TooLong, 46))
+        );
+        assert_eq!(consumed, data.len());
+
+        let parser = NmeaParser::new();
+        assert_eq!(parser.max_sentence_length(), DEFAULT_MAX_SENTENCE_LEN);
+        assert!(parser.parse_bytes(data).is_ok());
+    }
+
+    #[test]
+    fn test_raised_limit_long_proprietary_sentence() {
+        let data = b"$PGRMX,0123456789,0123456789,0123456789,0123456789,0123456789,\
+                     0123456789,0123456789,0123456789*50\r\n";
+        let parser = NmeaParser::builder().max_sentence_length(128).build();
+
+        // Too long for the fixed GenericSentence capture
+        let (err, consumed) = parser.parse_bytes(data).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::InvalidMessage(ErrorDetail::at(ErrorReason::TooLong, MAX_GENERIC_LEN))
+        );
+        assert_eq!(consumed, data.len());
+
+        // Still available without copying
+        let (sentence, consumed) = parser.parse_bytes_ref(data).unwrap();
+        let sentence = sentence.unwrap();
+        assert_eq!(sentence.message_type(), MessageType::Proprietary);
+        assert_eq!(sentence.field(7), Some("0123456789"));
+        assert_eq!(consumed, data.len());
+    }
+
+    /// UBX frame whose payload contains a '$'
+    const UBX_FRAME: &[u8] = b"\xB5\x62\x06\x01\x02\x00$\x01\x2E\x7A";
+
+    #[test]
+    fn test_ubx_frames_skipped_atomically() {
+        let parser = NmeaParser::new();
+        let mut data = [0u8; 52];
+        data[..10].copy_from_slice(UBX_FRAME);
+        data[10..].copy_from_slice(b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n");
+
+        let mut iter = parser.iter(&data);
+        let (result, offset) = iter.next().unwrap();
+        assert_eq!(result.unwrap().message_type(), MessageType::GLL);
+        assert_eq!(offset, data.len());
+        assert!(iter.next().is_none());
+
+        // A partial frame is kept for the next call
+        let (msg, consumed) = parser.parse_bytes(&data[..7]).unwrap();
+        assert!(msg.is_none());
+        assert_eq!(consumed, 0);
+
+        // A partial frame followed by a complete sentence is given up
+        let bogus = b"\xB5\x62\x01\x01\x01\x01$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+        let (msg, consumed) = parser.parse_bytes(bogus).unwrap();
+        assert!(msg.is_none());
+        assert_eq!(consumed, 1);
+        let (msg, consumed) = parser.parse_bytes(&bogus[consumed..]).unwrap();
+        assert_eq!(msg.unwrap().message_type(), MessageType::GLL);
+        assert_eq!(consumed, bogus.len() - 1);
+
+        let mut out: [Option<NmeaMessage>; 2] = Default::default();
+        assert_eq!(parser.parse_all(bogus, &mut out), (1, bogus.len()));
+        assert_eq!(out[0].as_ref().unwrap().message_type(), MessageType::GLL);
+
+        // A corrupted frame is spurious data
+        let mut corrupted = [0u8; 10];
+        corrupted.copy_from_slice(UBX_FRAME);
+        corrupted[9] ^= 0xFF;
+        let (msg, consumed) = parser.parse_bytes(&corrupted).unwrap();
+        assert!(msg.is_none());
+        assert_eq!(consumed, 1);
+    }
+
+    #[test]
+    fn test_ubx_skipping_disabled() {
+        let parser = NmeaParser::builder().skip_ubx_frames(false).build();
+        assert!(!parser.skips_ubx_frames());
+
+        // The '$' in the payload is taken for a sentence start
+        let (msg, consumed) = parser.parse_bytes(UBX_FRAME).unwrap();
+        assert!(msg.is_none());
+        assert_eq!(consumed, 6);
+    }
+
+    #[test]
+    fn test_raw_sentence_bytes() {
+        let parser = NmeaParser::new();
+        let data = b"\\s:src\\$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n$GPGLL,4916";
+
+        let (msg, raw, consumed) = parser.parse_bytes_raw(data).unwrap();
+        assert_eq!(msg.unwrap().message_type(), MessageType::GLL);
+        let raw = raw.unwrap();
+        assert_eq!(raw, &data[7..47]);
+        assert_eq!(checksum::verify(raw, ChecksumPolicy::Strict), Ok(()));
+        assert_eq!(consumed, 49);
+
+        let (msg, raw, consumed) = parser.parse_bytes_raw(&data[consumed..]).unwrap();
+        assert!(msg.is_none());
+        assert!(raw.is_none());
+        assert_eq!(consumed, 0);
+    }
 }
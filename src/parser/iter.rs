@@ -0,0 +1,157 @@
+//! Iterator over the messages contained in a byte buffer
+
+use crate::decoder::{MessageDecoder, NoDecoder};
+use crate::parser::NmeaParser;
+use crate::types::ParseError;
+
+/// Iterator repeatedly applying `NmeaParser::parse_bytes` to a byte buffer
+///
+/// Each item is the parse outcome of one complete sentence together with the
+/// offset in the buffer just past that sentence. Spurious data between
+/// sentences is skipped. Iteration stops at the end of the buffer or at a
+/// trailing partial sentence; `consumed()` then tells how many bytes were
+/// processed, so the remainder can be kept for the next chunk.
+pub struct NmeaIter<'p, 'd, D = NoDecoder> {
+    parser: &'p NmeaParser<D>,
+    data: &'d [u8],
+    offset: usize,
+}
+
+impl<'p, 'd, D> NmeaIter<'p, 'd, D> {
+    pub(crate) fn new(parser: &'p NmeaParser<D>, data: &'d [u8]) -> Self {
+        NmeaIter {
+            parser,
+            data,
+            offset: 0,
+        }
+    }
+
+    /// Number of bytes processed so far
+    pub fn consumed(&self) -> usize {
+        self.offset
+    }
+
+    /// Bytes not processed yet (e.g. a trailing partial sentence)
+    pub fn remaining(&self) -> &'d [u8] {
+        &self.data[self.offset..]
+    }
+}
+
+impl<D: MessageDecoder> Iterator for NmeaIter<'_, '_, D> {
+    type Item = (Result<D::Message, ParseError>, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.offset < self.data.len() {
+            match self.parser.parse_bytes(&self.data[self.offset..]) {
+                Ok((Some(msg), consumed)) => {
+                    self.offset += consumed;
+                    return Some((Ok(msg), self.offset));
+                }
+                Ok((None, 0)) => {
+                    // Partial sentence, need more data
+                    return None;
+                }
+                Ok((None, consumed)) => {
+                    // Spurious data consumed
+                    self.offset += consumed;
+                }
+                Err((err, consumed)) => {
+                    self.offset += consumed;
+                    return Some((Err(err), self.offset));
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::message::GenericSentence;
+    use crate::types::{ErrorReason, MessageType, ParseError};
+    use crate::{NmeaParser, SentenceDecoder};
+
+    #[test]
+    fn test_iter_multiple_messages() {
+        let parser = NmeaParser::new();
+        let data = b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n\
+                     $GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+
+        let mut iter = parser.iter(data);
+
+        let (msg, offset) = iter.next().unwrap();
+        assert_eq!(msg.unwrap().message_type(), MessageType::GGA);
+        assert_eq!(offset, 67);
+
+        let (msg, offset) = iter.next().unwrap();
+        assert_eq!(msg.unwrap().message_type(), MessageType::GLL);
+        assert_eq!(offset, data.len());
+
+        assert!(iter.next().is_none());
+        assert_eq!(iter.consumed(), data.len());
+    }
+
+    #[test]
+    fn test_iter_skips_spurious_data_and_reports_errors() {
+        let parser = NmeaParser::new();
+        let data = b"noise$GPGLL,4916.45,N,12311.12,W,225444,A,*00\r\n\
+                     garbage$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+
+        let results: [_; 2] = {
+            let mut iter = parser.iter(data);
+            [iter.next().unwrap().0, iter.next().unwrap().0]
+        };
+
+        assert!(matches!(
+            results[0].as_ref().unwrap_err(),
+            ParseError::InvalidChecksum(_)
+        ));
+        assert!(results[1].is_ok());
+    }
+
+    #[test]
+    fn test_iter_stops_at_partial_sentence() {
+        let parser = NmeaParser::new();
+        let data = b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n$GPGGA,123519,480";
+
+        let mut iter = parser.iter(data);
+        assert!(iter.next().is_some());
+        assert!(iter.next().is_none());
+        assert_eq!(iter.remaining(), b"$GPGGA,123519,480");
+    }
+
+    #[test]
+    fn test_iter_with_decoder() {
+        struct WaterTemperature;
+
+        impl SentenceDecoder for WaterTemperature {
+            type Output = f32;
+
+            fn accepts(&self, sentence: &GenericSentence) -> bool {
+                sentence.sentence_formatter() == "MTW"
+            }
+
+            fn decode(&self, sentence: &GenericSentence) -> Option<f32> {
+                sentence.field(0)?.parse().ok()
+            }
+        }
+
+        let parser = NmeaParser::builder().decoder(WaterTemperature).build();
+        let data = b"$IIMTW,18.5,C*1F\r\n$IIMTW,,C\r\n\
+                     $GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n$IIMTW,1";
+
+        let mut iter = parser.iter(data);
+        let (decoded, _) = iter.next().unwrap();
+        assert_eq!(decoded.unwrap().as_custom(), Some(&18.5));
+        let (err, _) = iter.next().unwrap();
+        assert_eq!(err.unwrap_err().reason(), Some(ErrorReason::DecoderFailed));
+        let (decoded, _) = iter.next().unwrap();
+        let decoded = decoded.unwrap();
+        assert_eq!(
+            decoded.as_builtin().unwrap().message_type(),
+            MessageType::GLL
+        );
+        assert!(iter.next().is_none());
+        assert_eq!(iter.remaining(), b"$IIMTW,1");
+    }
+}
@@ -0,0 +1,317 @@
+//! Sentence routing to multiple sinks
+//!
+//! [`NmeaRouter`] parses a byte stream like `NmeaParser` and, for every valid
+//! sentence, forwards the raw sentence to each registered [`SentenceSink`]
+//! whose [`RouteFilter`] matches its talker ID and message type. This covers
+//! multiplexer-style applications, e.g. forwarding AIS (`$AI...`) sentences
+//! to one UART and GNSS sentences to another, while the parsed messages are
+//! still returned to the caller.
+
+use crate::parser::{NmeaParser, MAX_ACCEPTED_TALKERS};
+use crate::types::{MessageType, NmeaMessage, ParseError, TalkerId};
+use crate::ParseResult;
+
+/// Destination of routed sentences (UART, log, network socket, ...)
+pub trait SentenceSink {
+    /// Called with each matching sentence, from `$` to the checksum, without
+    /// TAG block and line ending
+    fn forward(&mut self, sentence: &[u8]);
+}
+
+/// Predicate selecting sentences by talker ID and message type
+///
+/// A filter without registered talkers or message types matches every
+/// sentence; otherwise a sentence must match one of the registered talkers
+/// (if any) and one of the registered message types (if any).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RouteFilter {
+    message_filter: Option<u16>,
+    talker_filter: [Option<TalkerId>; MAX_ACCEPTED_TALKERS],
+    gnss_only: bool,
+}
+
+impl RouteFilter {
+    /// Create a filter matching every sentence
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also match sentences of `message_type`
+    pub fn message_type(mut self, message_type: MessageType) -> Self {
+        self.message_filter = Some(self.message_filter.unwrap_or(0) | message_type.mask());
+        self
+    }
+
+    /// Also match sentences from `talker_id`
+    ///
+    /// At most `MAX_ACCEPTED_TALKERS` (8) talkers can be registered; further
+    /// talkers are ignored.
+    pub fn talker(mut self, talker_id: TalkerId) -> Self {
+        if let Some(slot) = self
+            .talker_filter
+            .iter_mut()
+            .find(|slot| slot.is_none() || **slot == Some(talker_id))
+        {
+            *slot = Some(talker_id);
+        }
+        self
+    }
+
+    /// Only match sentences from GNSS talkers (see `TalkerId::is_gnss`)
+    pub fn gnss(mut self) -> Self {
+        self.gnss_only = true;
+        self
+    }
+
+    /// Tell whether a parsed message matches the filter
+    pub fn matches(&self, msg: &NmeaMessage) -> bool {
+        self.matches_header(msg.talker_id(), msg.message_type())
+    }
+
+    /// Tell whether a sentence with the given header matches the filter
+    pub fn matches_header(&self, talker_id: TalkerId, message_type: MessageType) -> bool {
+        let type_ok = self
+            .message_filter
+            .map_or(true, |mask| mask & message_type.mask() != 0);
+        let talker_ok =
+            self.talker_filter[0].is_none() || self.talker_filter.contains(&Some(talker_id));
+        type_ok && talker_ok && (!self.gnss_only || talker_id.is_gnss())
+    }
+}
+
+/// Parser forwarding raw sentences to up to `N` sinks selected by filters
+///
+/// Every route whose filter matches receives the sentence, so a sentence can
+/// be forwarded to several sinks. Sentences with broken framing or a wrong
+/// checksum are not forwarded.
+///
+/// # Example
+///
+/// ```
+/// use rustedbytes_nmea::{NmeaRouter, RouteFilter, SentenceSink, TalkerId};
+///
+/// #[derive(Default)]
+/// struct Port(usize);
+///
+/// impl SentenceSink for Port {
+///     fn forward(&mut self, _sentence: &[u8]) {
+///         self.0 += 1;
+///     }
+/// }
+///
+/// let (mut ais_port, mut gnss_port) = (Port::default(), Port::default());
+///
+/// let mut router = NmeaRouter::<2>::new();
+/// router.add_route(RouteFilter::new().talker(TalkerId::AI), &mut ais_port);
+/// router.add_route(RouteFilter::new().gnss(), &mut gnss_port);
+///
+/// let data = b"$AIALR,000000.00,001,A,V,AIS: TX malfunction*7C\r\n\
+///              $GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+///
+/// let mut offset = 0;
+/// while let Ok((Some(_), consumed)) = router.route_bytes(&data[offset..]) {
+///     offset += consumed;
+/// }
+///
+/// assert_eq!(ais_port.0, 1);
+/// assert_eq!(gnss_port.0, 1);
+/// ```
+pub struct NmeaRouter<'a, const N: usize> {
+    parser: NmeaParser,
+    routes: [Option<(RouteFilter, &'a mut dyn SentenceSink)>; N],
+}
+
+impl<'a, const N: usize> NmeaRouter<'a, N> {
+    /// Create a router using a default `NmeaParser`
+    pub fn new() -> Self {
+        Self::with_parser(NmeaParser::new())
+    }
+
+    /// Create a router using a configured `NmeaParser`
+    pub fn with_parser(parser: NmeaParser) -> Self {
+        NmeaRouter {
+            parser,
+            routes: core::array::from_fn(|_| None),
+        }
+    }
+
+    /// Get the wrapped parser
+    pub fn parser(&self) -> &NmeaParser {
+        &self.parser
+    }
+
+    /// Register a sink receiving the sentences matching `filter`
+    ///
+    /// Returns `false` if all `N` routes are already in use.
+    pub fn add_route(&mut self, filter: RouteFilter, sink: &'a mut dyn SentenceSink) -> bool {
+        match self.routes.iter_mut().find(|route| route.is_none()) {
+            Some(slot) => {
+                *slot = Some((filter, sink));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Parse the next sentence like `NmeaParser::parse_bytes`, forwarding it
+    /// to the matching sinks when its framing and checksum are valid
+    ///
+    /// Sentences are routed on their header, before field decoding: a
+    /// sentence whose fields cannot be decoded (e.g. a GGA without fix) is
+    /// still forwarded, while its decoding error is returned.
+    pub fn route_bytes(&mut self, data: &[u8]) -> ParseResult {
+        let (sentence, consumed) = self.parser.parse_bytes_ref(data)?;
+        let Some(sentence) = sentence else {
+            return Ok((None, consumed));
+        };
+
+        for (filter, sink) in self.routes.iter_mut().flatten() {
+            if filter.matches_header(sentence.talker_id(), sentence.message_type()) {
+                sink.forward(sentence.as_bytes());
+            }
+        }
+
+        match self.parser.parse_sentence(sentence.as_bytes()) {
+            Ok(msg) => Ok((Some(msg), consumed)),
+            Err(detail) => Err((ParseError::InvalidMessage(detail), consumed)),
+        }
+    }
+}
+
+impl<const N: usize> Default for NmeaRouter<'_, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sink recording the number of sentences and the last one forwarded
+    struct Recorder {
+        count: usize,
+        last: [u8; 82],
+        last_len: usize,
+    }
+
+    impl Default for Recorder {
+        fn default() -> Self {
+            Recorder {
+                count: 0,
+                last: [0; 82],
+                last_len: 0,
+            }
+        }
+    }
+
+    impl Recorder {
+        fn last(&self) -> &[u8] {
+            &self.last[..self.last_len]
+        }
+    }
+
+    impl SentenceSink for Recorder {
+        fn forward(&mut self, sentence: &[u8]) {
+            self.count += 1;
+            self.last_len = sentence.len();
+            self.last[..sentence.len()].copy_from_slice(sentence);
+        }
+    }
+
+    fn route_all<const N: usize>(router: &mut NmeaRouter<'_, N>, data: &[u8]) -> usize {
+        let (mut offset, mut messages) = (0, 0);
+        while offset < data.len() {
+            match router.route_bytes(&data[offset..]) {
+                Ok((Some(_), consumed)) => {
+                    messages += 1;
+                    offset += consumed;
+                }
+                Ok((None, 0)) => break,
+                Ok((None, consumed)) | Err((_, consumed)) => offset += consumed,
+            }
+        }
+        messages
+    }
+
+    #[test]
+    fn test_route_by_talker_and_type() {
+        let data = b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n\
+                     $AIALR,000000.00,001,A,V,AIS: TX malfunction*7C\r\n\
+                     $GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n\
+                     $GPGLL,4916.45,N,12311.12,W,225444,A,*00\r\n\
+                     $IIMTW,18.5,C*1F\r\n";
+
+        let (mut ais, mut gll, mut all) = (
+            Recorder::default(),
+            Recorder::default(),
+            Recorder::default(),
+        );
+        let mut router = NmeaRouter::<4>::new();
+        assert!(router.add_route(RouteFilter::new().talker(TalkerId::AI), &mut ais));
+        assert!(router.add_route(
+            RouteFilter::new().gnss().message_type(MessageType::GLL),
+            &mut gll
+        ));
+        assert!(router.add_route(RouteFilter::new(), &mut all));
+
+        assert_eq!(route_all(&mut router, data), 4);
+
+        assert_eq!(ais.count, 1);
+        assert!(ais.last().starts_with(b"$AIALR,"));
+        assert_eq!(gll.count, 1);
+        assert_eq!(gll.last(), b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D");
+        // The sentence with a wrong checksum is not forwarded
+        assert_eq!(all.count, 4);
+        assert!(all.last().starts_with(b"$IIMTW,"));
+    }
+
+    #[test]
+    fn test_route_undecodable_sentence() {
+        // GGA without fix: valid framing and checksum, but no position
+        let data = b"$GPGGA,123519,,,,,0,00,,,M,,M,,*6B\r\n";
+
+        let mut gga = Recorder::default();
+        let mut router = NmeaRouter::<1>::new();
+        assert!(router.add_route(RouteFilter::new().message_type(MessageType::GGA), &mut gga));
+
+        let (err, consumed) = router.route_bytes(data).unwrap_err();
+        assert!(matches!(err, ParseError::InvalidMessage(_)));
+        assert_eq!(consumed, data.len());
+        assert_eq!(gga.count, 1);
+        assert_eq!(gga.last(), &data[..data.len() - 2]);
+    }
+
+    #[test]
+    fn test_route_capacity() {
+        let (mut first, mut second) = (Recorder::default(), Recorder::default());
+        let mut router = NmeaRouter::<1>::default();
+        assert!(router.add_route(RouteFilter::new(), &mut first));
+        assert!(!router.add_route(RouteFilter::new(), &mut second));
+    }
+
+    #[test]
+    fn test_filter_matches() {
+        let parser = NmeaParser::new();
+        let msg = parser
+            .parse_sentence_complete(b"$GNGLL,4916.45,N,12311.12,W,225444,A,*03\r\n")
+            .unwrap();
+
+        assert!(RouteFilter::new().matches(&msg));
+        assert!(RouteFilter::new().gnss().matches(&msg));
+        assert!(RouteFilter::new()
+            .talker(TalkerId::GP)
+            .talker(TalkerId::GN)
+            .matches(&msg));
+        assert!(!RouteFilter::new().talker(TalkerId::GP).matches(&msg));
+        assert!(!RouteFilter::new()
+            .message_type(MessageType::GGA)
+            .matches(&msg));
+        assert!(RouteFilter::new()
+            .gnss()
+            .matches_header(TalkerId::GP, MessageType::GGA));
+        assert!(!RouteFilter::new()
+            .gnss()
+            .matches_header(TalkerId::AI, MessageType::Unknown));
+    }
+}
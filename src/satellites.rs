@@ -0,0 +1,417 @@
+//! Satellite tracking table
+//!
+//! [`SatelliteTable`] merges the satellites in view reported by GSV sentences
+//! with the satellites used in the fix reported by GSA sentences, across all
+//! GNSS talkers.
+//!
+//! Satellites are identified by constellation and PRN. The constellation is
+//! taken from the talker ID (`GP`, `GL`, `GA`, ...); for combined `GN`
+//! sentences the GSA System ID field is used when present, and otherwise the
+//! legacy NMEA PRN ranges (1-64 GPS/SBAS, 65-96 GLONASS).
+//!
+//! When the last message of a GSV group is received, the satellites of its
+//! talker that were reported by the previous group but not by this one are
+//! dropped from the view. NMEA 4.11 receivers send one GSV group per signal;
+//! a satellite stays in view while the group of any of its signals reports
+//! it, and the values of the most recently reported signal are kept.
+//!
+//! Each GSA sentence replaces the used-in-fix flags of the constellations it
+//! covers.
+
+use crate::handler::MessageHandler;
+use crate::message::{GsaData, GsvData};
+use crate::types::{MessageType, NmeaMessage, SystemId, TalkerId};
+
+/// A satellite tracked by a `SatelliteTable`
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Satellite {
+    pub system: SystemId,
+    pub prn: u8,
+    pub elevation: Option<u16>,
+    pub azimuth: Option<u16>,
+    pub snr: Option<u8>,
+    /// Reported in view by the latest GSV cycle
+    pub in_view: bool,
+    /// Reported as used in the fix by the latest GSA sentence
+    pub used: bool,
+    talker: Option<TalkerId>,
+    /// Signals whose GSV group reports the satellite in view
+    signals: u16,
+    /// Signals whose GSV group in progress reported the satellite
+    reported: u16,
+}
+
+/// Stateful table of up to `N` satellites built from GSV and GSA sentences
+///
+/// Satellites beyond the capacity are ignored until an entry is freed.
+///
+/// # Example
+///
+/// ```
+/// use rustedbytes_nmea::{NmeaParser, SatelliteTable, SystemId};
+///
+/// let parser = NmeaParser::new();
+/// let data = b"$GPGSV,1,1,03,04,40,083,46,05,17,308,41,09,07,344,39*49\r\n\
+///              $GPGSA,A,3,04,05,,,,,,,,,,,2.5,1.3,2.1*35\r\n";
+///
+/// let mut table = SatelliteTable::<32>::new();
+/// parser.parse_into(data, &mut table);
+///
+/// assert_eq!(table.visible_count(), 3);
+/// assert_eq!(table.used_count(), 2);
+/// assert!(table.get(SystemId::Gps, 4).unwrap().used);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SatelliteTable<const N: usize> {
+    entries: [Option<Satellite>; N],
+}
+
+impl<const N: usize> SatelliteTable<N> {
+    /// Create an empty table
+    pub fn new() -> Self {
+        SatelliteTable { entries: [None; N] }
+    }
+
+    /// Update the table from a parsed message
+    ///
+    /// Messages other than GSV and GSA are ignored.
+    pub fn update(&mut self, msg: &NmeaMessage) {
+        match msg {
+            NmeaMessage::GSV(gsv) => self.update_gsv(gsv),
+            NmeaMessage::GSA(gsa) => self.update_gsa(gsa),
+            _ => {}
+        }
+    }
+
+    /// Update the satellites in view from a GSV sentence
+    pub fn update_gsv(&mut self, gsv: &GsvData) {
+        let signal = signal_bit(gsv.signal_id);
+        if gsv.message_num == 1 {
+            for sat in self.entries.iter_mut().flatten() {
+                if sat.talker == Some(gsv.talker_id) {
+                    sat.reported &= !signal;
+                }
+            }
+        }
+
+        for info in gsv.satellite_info.iter().flatten() {
+            let Some(prn) = info.prn else {
+                continue;
+            };
+            let system = system_of(gsv.talker_id, None, prn);
+            if let Some(sat) = self.entry(system, prn) {
+                sat.elevation = info.elevation;
+                sat.azimuth = info.azimuth;
+                sat.snr = info.snr;
+                sat.in_view = true;
+                sat.talker = Some(gsv.talker_id);
+                sat.signals |= signal;
+                sat.reported |= signal;
+            }
+        }
+
+        if gsv.message_num == gsv.num_messages {
+            for sat in self.entries.iter_mut().flatten() {
+                if sat.talker != Some(gsv.talker_id) || sat.reported & signal != 0 {
+                    continue;
+                }
+                sat.signals &= !signal;
+                if sat.signals == 0 {
+                    sat.in_view = false;
+                    sat.elevation = None;
+                    sat.azimuth = None;
+                    sat.snr = None;
+                }
+            }
+            self.remove_untracked();
+        }
+    }
+
+    /// Update the satellites used in the fix from a GSA sentence
+    pub fn update_gsa(&mut self, gsa: &GsaData) {
+        let prns = gsa.satellite_ids.iter().flatten();
+
+        let mut systems = system_bit(system_of(gsa.talker_id, gsa.system_id, 0));
+        for &prn in prns.clone() {
+            systems |= system_bit(system_of(gsa.talker_id, gsa.system_id, prn));
+        }
+
+        for sat in self.entries.iter_mut().flatten() {
+            if systems & system_bit(sat.system) != 0 {
+                sat.used = false;
+            }
+        }
+
+        for &prn in prns {
+            let system = system_of(gsa.talker_id, gsa.system_id, prn);
+            if let Some(sat) = self.entry(system, prn) {
+                sat.used = true;
+            }
+        }
+
+        self.remove_untracked();
+    }
+
+    /// Iterate over the tracked satellites
+    pub fn satellites(&self) -> impl Iterator<Item = &Satellite> {
+        self.entries.iter().flatten()
+    }
+
+    /// Look up a satellite by constellation and PRN
+    pub fn get(&self, system: SystemId, prn: u8) -> Option<&Satellite> {
+        self.satellites()
+            .find(|sat| sat.system == system && sat.prn == prn)
+    }
+
+    /// Number of satellites in view
+    pub fn visible_count(&self) -> usize {
+        self.satellites().filter(|sat| sat.in_view).count()
+    }
+
+    /// Number of satellites used in the fix
+    pub fn used_count(&self) -> usize {
+        self.satellites().filter(|sat| sat.used).count()
+    }
+
+    /// Remove all satellites
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Find or insert the entry for a satellite, `None` if the table is full
+    fn entry(&mut self, system: SystemId, prn: u8) -> Option<&mut Satellite> {
+        let index = self
+            .entries
+            .iter()
+            .position(|e| matches!(e, Some(sat) if sat.system == system && sat.prn == prn))
+            .or_else(|| self.entries.iter().position(Option::is_none))?;
+
+        Some(self.entries[index].get_or_insert(Satellite {
+            system,
+            prn,
+            elevation: None,
+            azimuth: None,
+            snr: None,
+            in_view: false,
+            used: false,
+            talker: None,
+            signals: 0,
+            reported: 0,
+        }))
+    }
+
+    /// Drop satellites neither in view nor used in the fix
+    fn remove_untracked(&mut self) {
+        for entry in self.entries.iter_mut() {
+            if matches!(entry, Some(sat) if !sat.in_view && !sat.used) {
+                *entry = None;
+            }
+        }
+    }
+}
+
+impl<const N: usize> Default for SatelliteTable<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> MessageHandler for SatelliteTable<N> {
+    fn wants(&self, message_type: MessageType) -> bool {
+        matches!(message_type, MessageType::GSV | MessageType::GSA)
+    }
+
+    fn on_gsv(&mut self, data: &GsvData) {
+        self.update_gsv(data);
+    }
+
+    fn on_gsa(&mut self, data: &GsaData) {
+        self.update_gsa(data);
+    }
+}
+
+/// Determine the constellation of a satellite
+fn system_of(talker: TalkerId, system_id: Option<SystemId>, prn: u8) -> SystemId {
+    match talker {
+        TalkerId::GP => SystemId::Gps,
+        TalkerId::GL => SystemId::Glonass,
+        TalkerId::GA => SystemId::Galileo,
+        TalkerId::GB | TalkerId::BD => SystemId::BeiDou,
+        TalkerId::QZ | TalkerId::GQ => SystemId::Qzss,
+        TalkerId::GI => SystemId::NavIc,
+        _ => match system_id {
+            Some(system) if system != SystemId::Unknown => system,
+            _ => match prn {
+                1..=64 => SystemId::Gps,
+                65..=96 => SystemId::Glonass,
+                _ => SystemId::Unknown,
+            },
+        },
+    }
+}
+
+/// Bit of a GSV signal ID in the signal sets of a satellite
+fn signal_bit(signal_id: Option<u8>) -> u16 {
+    1 << (signal_id.unwrap_or(0) & 0x0F)
+}
+
+fn system_bit(system: SystemId) -> u8 {
+    match system {
+        SystemId::Gps => 1 << 0,
+        SystemId::Glonass => 1 << 1,
+        SystemId::Galileo => 1 << 2,
+        SystemId::BeiDou => 1 << 3,
+        SystemId::Qzss => 1 << 4,
+        SystemId::NavIc => 1 << 5,
+        SystemId::Unknown => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NmeaParser;
+
+    fn feed<const N: usize>(table: &mut SatelliteTable<N>, data: &[u8]) {
+        let consumed = NmeaParser::new().parse_into(data, table);
+        assert_eq!(consumed, data.len());
+    }
+
+    #[test]
+    fn test_gsv_and_gsa_merged() {
+        let mut table = SatelliteTable::<32>::new();
+        feed(
+            &mut table,
+            b"$GPGSV,2,1,05,04,40,083,46,05,17,308,41,09,07,344,39,12,22,228,45*76\r\n\
+              $GPGSV,2,2,05,24,10,020,*49\r\n\
+              $GPGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39\r\n",
+        );
+
+        assert_eq!(table.visible_count(), 5);
+        assert_eq!(table.used_count(), 5);
+
+        let sat = table.get(SystemId::Gps, 5).unwrap();
+        assert_eq!(sat.elevation, Some(17));
+        assert_eq!(sat.azimuth, Some(308));
+        assert_eq!(sat.snr, Some(41));
+        assert!(sat.in_view && sat.used);
+
+        assert_eq!(table.get(SystemId::Gps, 24).unwrap().snr, None);
+    }
+
+    #[test]
+    fn test_multiple_constellations() {
+        let mut table = SatelliteTable::<32>::new();
+        feed(
+            &mut table,
+            b"$GPGSV,1,1,02,04,40,083,46,05,17,308,41*7F\r\n\
+              $GLGSV,1,1,02,04,35,120,38,05,55,210,42*6D\r\n\
+              $GNGSA,A,3,04,,,,,,,,,,,,2.0,1.0,1.7,1*30\r\n\
+              $GNGSA,A,3,05,,,,,,,,,,,,2.0,1.0,1.7,2*32\r\n",
+        );
+
+        assert_eq!(table.visible_count(), 4);
+        assert_eq!(table.used_count(), 2);
+        assert!(table.get(SystemId::Gps, 4).unwrap().used);
+        assert!(!table.get(SystemId::Gps, 5).unwrap().used);
+        assert!(!table.get(SystemId::Glonass, 4).unwrap().used);
+        assert!(table.get(SystemId::Glonass, 5).unwrap().used);
+    }
+
+    #[test]
+    fn test_gsa_replaces_used_flags() {
+        let mut table = SatelliteTable::<32>::new();
+        feed(
+            &mut table,
+            b"$GPGSV,1,1,02,04,40,083,46,05,17,308,41*7F\r\n\
+              $GPGSA,A,3,04,05,,,,,,,,,,,2.5,1.3,2.1*35\r\n\
+              $GPGSA,A,3,05,,,,,,,,,,,,2.5,1.3,2.1*31\r\n",
+        );
+
+        assert_eq!(table.used_count(), 1);
+        assert!(!table.get(SystemId::Gps, 4).unwrap().used);
+    }
+
+    #[test]
+    fn test_stale_satellites_dropped_at_end_of_cycle() {
+        let mut table = SatelliteTable::<32>::new();
+        feed(
+            &mut table,
+            b"$GPGSV,1,1,02,04,40,083,46,05,17,308,41*7F\r\n",
+        );
+        assert_eq!(table.visible_count(), 2);
+
+        // Satellite 4 is missing from the second cycle
+        feed(&mut table, b"$GPGSV,1,1,01,05,18,309,40*4A\r\n");
+        assert_eq!(table.visible_count(), 1);
+        assert!(table.get(SystemId::Gps, 4).is_none());
+        assert_eq!(table.get(SystemId::Gps, 5).unwrap().azimuth, Some(309));
+    }
+
+    #[test]
+    fn test_stale_satellites_kept_until_last_message() {
+        let mut table = SatelliteTable::<32>::new();
+        feed(
+            &mut table,
+            b"$GPGSV,2,1,05,04,40,083,46,05,17,308,41,09,07,344,39,12,22,228,45*76\r\n\
+              $GPGSV,2,2,05,24,10,020,*49\r\n",
+        );
+        assert_eq!(table.visible_count(), 5);
+
+        // Satellite 24 is only known to be gone once the group is complete
+        feed(
+            &mut table,
+            b"$GPGSV,2,1,04,04,40,083,46,05,17,308,41,09,07,344,39,12,22,228,45*77\r\n",
+        );
+        assert_eq!(table.visible_count(), 5);
+        feed(&mut table, b"$GPGSV,2,2,04*7D\r\n");
+        assert_eq!(table.visible_count(), 4);
+        assert!(table.get(SystemId::Gps, 24).is_none());
+    }
+
+    #[test]
+    fn test_signal_groups_merged() {
+        let mut table = SatelliteTable::<32>::new();
+        feed(
+            &mut table,
+            b"$GPGSV,1,1,02,04,40,083,46,05,17,308,41,1*62\r\n\
+              $GPGSV,1,1,01,05,17,308,35,8*52\r\n\
+              $GPGSV,1,1,02,04,40,083,45,05,17,308,40,1*60\r\n",
+        );
+
+        assert_eq!(table.visible_count(), 2);
+        assert_eq!(table.get(SystemId::Gps, 4).unwrap().snr, Some(45));
+
+        // Satellite 5 is no longer reported on signal 1 but still on signal 8
+        feed(&mut table, b"$GPGSV,1,1,01,04,40,083,45,1*5F\r\n");
+        assert_eq!(table.visible_count(), 2);
+        feed(&mut table, b"$GPGSV,1,1,01,05,17,308,35,8*52\r\n");
+        assert_eq!(table.visible_count(), 2);
+
+        // Satellite 5 is gone once the group of its last signal is complete
+        feed(
+            &mut table,
+            b"$GPGSV,1,1,01,04,40,083,45,1*5F\r\n\
+              $GPGSV,1,1,00,8*6D\r\n",
+        );
+        assert_eq!(table.visible_count(), 1);
+        assert!(table.get(SystemId::Gps, 5).is_none());
+    }
+
+    #[test]
+    fn test_capacity_limit() {
+        let mut table = SatelliteTable::<2>::new();
+        feed(
+            &mut table,
+            b"$GPGSV,1,1,03,04,40,083,46,05,17,308,41,09,07,344,39*49\r\n",
+        );
+
+        assert_eq!(table.visible_count(), 2);
+        assert!(table.get(SystemId::Gps, 9).is_none());
+
+        table.clear();
+        assert_eq!(table.satellites().count(), 0);
+    }
+}
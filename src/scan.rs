@@ -0,0 +1,110 @@
+//! Word-at-a-time delimiter scanning
+//!
+//! Framing a sentence means searching for a handful of delimiter bytes (`$`,
+//! `\`, `*`, `,` and line endings). Instead of testing every byte, the helpers
+//! in this module load a whole machine word at a time and use the classic
+//! "has zero byte" bit trick (SWAR) to test all of its bytes at once, falling
+//! back to a byte scan only inside the word that contains a match and for the
+//! trailing bytes that do not fill a word.
+
+const WORD: usize = core::mem::size_of::<usize>();
+/// `0x0101...01`
+const LO: usize = usize::MAX / 0xFF;
+/// `0x8080...80`
+const HI: usize = LO * 0x80;
+
+/// Check whether any byte of `word` equals `byte`
+///
+/// The bit trick can flag bytes above a real match, but never flags a word
+/// without one, so the result is exact.
+#[inline]
+fn contains(word: usize, byte: u8) -> bool {
+    let x = word ^ (LO * byte as usize);
+    x.wrapping_sub(LO) & !x & HI != 0
+}
+
+/// Find the position of the first byte of `data` equal to any of `needles`
+#[inline]
+pub(crate) fn find_any<const K: usize>(data: &[u8], needles: [u8; K]) -> Option<usize> {
+    let mut pos = 0;
+
+    for chunk in data.chunks_exact(WORD) {
+        let mut bytes = [0u8; WORD];
+        bytes.copy_from_slice(chunk);
+        let word = usize::from_ne_bytes(bytes);
+        if needles.iter().any(|&needle| contains(word, needle)) {
+            break;
+        }
+        pos += WORD;
+    }
+
+    data[pos..]
+        .iter()
+        .position(|byte| needles.contains(byte))
+        .map(|offset| pos + offset)
+}
+
+/// Find the position of the first byte of `data` equal to `needle`
+#[inline]
+pub(crate) fn find(data: &[u8], needle: u8) -> Option<usize> {
+    find_any(data, [needle])
+}
+
+/// XOR all bytes of `data` together, a word at a time
+#[inline]
+pub(crate) fn xor_bytes(data: &[u8]) -> u8 {
+    let chunks = data.chunks_exact(WORD);
+    let tail = chunks.remainder();
+
+    let mut acc = 0usize;
+    for chunk in chunks {
+        let mut bytes = [0u8; WORD];
+        bytes.copy_from_slice(chunk);
+        acc ^= usize::from_ne_bytes(bytes);
+    }
+
+    let folded = acc.to_ne_bytes().iter().fold(0u8, |acc, &b| acc ^ b);
+    tail.iter().fold(folded, |acc, &b| acc ^ b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_matches_byte_scan() {
+        let data = b"garbage before the start $GPGLL,4916.45,N,12311.12,W*1D\r\n";
+
+        for start in 0..data.len() {
+            let slice = &data[start..];
+            for needle in [b'$', b'*', b',', b'\r', b'\n', b'#'] {
+                assert_eq!(find(slice, needle), slice.iter().position(|&b| b == needle));
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_any_first_of_several() {
+        assert_eq!(find_any(b"0123456789abcdef\n\r", [b'\r', b'\n']), Some(16));
+        assert_eq!(find_any(b"0123456789abcdef", [b'\r', b'\n']), None);
+        assert_eq!(find_any(b"", [b'$']), None);
+    }
+
+    #[test]
+    fn test_find_high_bytes() {
+        // Bytes above 0x80 must not be mistaken for a match
+        let data = [0xFF, 0x80, 0xA4, 0x81, 0xFE, 0x7F, 0x00, 0x01, b'$'];
+        assert_eq!(find(&data, b'$'), Some(8));
+        assert_eq!(find(&data, 0x00), Some(6));
+    }
+
+    #[test]
+    fn test_xor_bytes_matches_fold() {
+        let data = b"GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,";
+
+        for end in 0..data.len() {
+            let slice = &data[..end];
+            assert_eq!(xor_bytes(slice), slice.iter().fold(0, |acc, &b| acc ^ b));
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! `serde` implementations that cannot be derived
+//!
+//! Enabled with the `serde` feature. Fixed-capacity text fields and talker IDs
+//! are serialized as strings; unsupported and proprietary sentences as their
+//! talker ID and raw sentence.
+
+use core::fmt;
+
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::fixed_str::FixedStr;
+use crate::message::{GenericSentence, MAX_GENERIC_LEN};
+use crate::types::TalkerId;
+
+impl<const N: usize> Serialize for FixedStr<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for FixedStr<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FixedStrVisitor<const N: usize>;
+
+        impl<const N: usize> Visitor<'_> for FixedStrVisitor<N> {
+            type Value = FixedStr<N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a string of at most {} bytes", N)
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                if value.len() > N {
+                    return Err(E::invalid_length(value.len(), &self));
+                }
+                Ok(FixedStr::new(value.as_bytes()))
+            }
+        }
+
+        deserializer.deserialize_str(FixedStrVisitor)
+    }
+}
+
+impl Serialize for TalkerId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for TalkerId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let id = FixedStr::<2>::deserialize(deserializer)?;
+        if id.as_bytes().len() != 2 {
+            return Err(de::Error::invalid_length(id.as_bytes().len(), &"2"));
+        }
+        Ok(TalkerId::from_bytes(id.as_bytes()))
+    }
+}
+
+/// Serialized form of a `GenericSentence`
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "GenericSentence")]
+struct GenericRepr {
+    talker_id: TalkerId,
+    sentence: FixedStr<MAX_GENERIC_LEN>,
+}
+
+impl Serialize for GenericSentence {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        GenericRepr {
+            talker_id: self.talker_id,
+            sentence: FixedStr::new(self.as_bytes()),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GenericSentence {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = GenericRepr::deserialize(deserializer)?;
+        let sentence = repr.sentence.as_bytes();
+        if !sentence.starts_with(b"$") {
+            return Err(de::Error::invalid_value(
+                de::Unexpected::Str(repr.sentence.as_str()),
+                &"a sentence starting with '$'",
+            ));
+        }
+        GenericSentence::from_sentence(sentence, repr.talker_id)
+            .ok_or_else(|| de::Error::invalid_length(sentence.len(), &"a shorter sentence"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{NmeaMessage, NmeaParser};
+
+    fn round_trip(sentence: &[u8]) -> (serde_json::Value, NmeaMessage) {
+        let (msg, _) = NmeaParser::new().parse_bytes(sentence).unwrap();
+        let json = serde_json::to_value(msg.unwrap()).unwrap();
+        let back: NmeaMessage = serde_json::from_value(json.clone()).unwrap();
+        (json, back)
+    }
+
+    #[test]
+    fn test_gga_to_json() {
+        let (json, back) = round_trip(
+            b"$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,0120*44\r\n",
+        );
+
+        let gga = &json["GGA"];
+        assert_eq!(gga["talker_id"], "GP");
+        assert_eq!(gga["time"]["hour"], 12);
+        assert_eq!(gga["fix_quality"], "Gps");
+        assert_eq!(gga["diff_station_id"], "0120");
+
+        let gga = back.as_gga().unwrap();
+        assert_eq!(gga.diff_station_id(), Some("0120"));
+        assert_eq!(gga.num_satellites, Some(8));
+    }
+
+    #[test]
+    fn test_generic_sentence_round_trip() {
+        let (json, back) = round_trip(b"$GPTXT,01,01,02,ANTSTATUS=OK*3B\r\n");
+
+        assert_eq!(
+            json["Unknown"]["sentence"],
+            "$GPTXT,01,01,02,ANTSTATUS=OK*3B"
+        );
+        let generic = back.as_unknown().unwrap();
+        assert_eq!(generic.field(3), Some("ANTSTATUS=OK"));
+    }
+
+    #[test]
+    fn test_unknown_talker_as_string() {
+        let (json, back) = round_trip(b"$XXGLL,4916.45,N,12311.12,W,225444,A*26\r\n");
+
+        assert_eq!(json["GLL"]["talker_id"], "XX");
+        assert_eq!(back.talker_id().as_str(), "XX");
+    }
+
+    #[test]
+    fn test_invalid_input_rejected() {
+        let json = serde_json::json!({"Unknown": {"talker_id": "GP", "sentence": "GPTXT"}});
+        assert!(serde_json::from_value::<NmeaMessage>(json).is_err());
+
+        let json = serde_json::json!({"Query": {
+            "talker_id": "CCC", "listener_id": "GP", "sentence": "GGA"
+        }});
+        assert!(serde_json::from_value::<NmeaMessage>(json).is_err());
+    }
+}
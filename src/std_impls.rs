@@ -0,0 +1,96 @@
+//! `std`-only conveniences: owned `String` getters
+//!
+//! Enabled with the `std` feature.
+
+use std::string::{String, ToString};
+
+use crate::message::{GgaData, GllData, GnsData, QueryData, RmcData};
+use crate::tag_block::TagBlock;
+
+impl GgaData {
+    /// Get UTC time as owned `hh:mm:ss[.sss]` string
+    pub fn time_string(&self) -> String {
+        self.time().to_string()
+    }
+
+    /// Get differential station ID as owned string (if present)
+    pub fn diff_station_id_string(&self) -> Option<String> {
+        self.diff_station_id().map(ToString::to_string)
+    }
+}
+
+impl RmcData {
+    /// Get UTC time as owned `hh:mm:ss[.sss]` string
+    pub fn time_string(&self) -> String {
+        self.time().to_string()
+    }
+
+    /// Get date as owned `yyyy-mm-dd` string
+    pub fn date_string(&self) -> String {
+        self.date().to_string()
+    }
+}
+
+impl GllData {
+    /// Get UTC time as owned `hh:mm:ss[.sss]` string
+    pub fn time_string(&self) -> String {
+        self.time().to_string()
+    }
+}
+
+impl GnsData {
+    /// Get UTC time as owned `hh:mm:ss[.sss]` string
+    pub fn time_string(&self) -> String {
+        self.time().to_string()
+    }
+
+    /// Get mode indicator as owned string
+    pub fn mode_indicator_string(&self) -> String {
+        self.mode_indicator().to_string()
+    }
+
+    /// Get differential station ID as owned string (if present)
+    pub fn diff_station_id_string(&self) -> Option<String> {
+        self.diff_station_id().map(ToString::to_string)
+    }
+}
+
+impl QueryData {
+    /// Get the requested sentence formatter as owned string
+    pub fn requested_sentence_string(&self) -> String {
+        self.requested_sentence().to_string()
+    }
+}
+
+impl TagBlock {
+    /// Get source identifier as owned string (if present)
+    pub fn source_string(&self) -> Option<String> {
+        self.source().map(ToString::to_string)
+    }
+
+    /// Get destination identifier as owned string (if present)
+    pub fn destination_string(&self) -> Option<String> {
+        self.destination().map(ToString::to_string)
+    }
+
+    /// Get free text as owned string (if present)
+    pub fn text_string(&self) -> Option<String> {
+        self.text().map(ToString::to_string)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::NmeaParser;
+
+    #[test]
+    fn test_owned_getters() {
+        let parser = NmeaParser::new();
+        let sentence = b"$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A\r\n";
+
+        let msg = parser.parse_sentence_complete(sentence).unwrap();
+        let rmc = msg.as_rmc().unwrap();
+        assert_eq!(rmc.time_string(), "12:35:19");
+        assert_eq!(rmc.date_string(), "1994-03-23");
+    }
+}
@@ -0,0 +1,431 @@
+//! Buffered stream parsing
+//!
+//! [`StreamParser`] owns a fixed-size buffer that collects arbitrary byte
+//! chunks (e.g. from a UART or DMA transfer) and yields complete messages,
+//! carrying partial sentences over to the next chunk.
+
+use crate::clock::{Clock, Timestamped};
+use crate::decoder::{MessageDecoder, NoDecoder};
+use crate::parser::NmeaParser;
+use crate::types::ParseError;
+use crate::ubx;
+
+/// Stateful wrapper around `NmeaParser` owning an `N`-byte receive buffer
+///
+/// A sentence longer than the buffer can never complete; when the buffer is
+/// full without containing a line ending its content is discarded. A UBX
+/// header claiming a frame longer than the buffer is dropped as noise (only
+/// its sync byte), so the sentences buffered behind it are still parsed.
+///
+/// The buffered bytes always form a contiguous slice handed to `NmeaParser`.
+/// Extracting a message only advances a read offset; the remaining partial
+/// sentence is moved to the front of the buffer when room is needed at its
+/// end, so bytes are not shifted on every extracted message.
+///
+/// # Example
+///
+/// ```
+/// use rustedbytes_nmea::{MessageType, StreamParser};
+///
+/// let mut stream = StreamParser::<128>::new();
+///
+/// // Sentence split across two chunks
+/// stream.feed(b"$GPGLL,4916.45,N,1231");
+/// assert!(stream.next_message().is_none());
+///
+/// stream.feed(b"1.12,W,225444,A,*1D\r\n");
+/// let msg = stream.next_message().unwrap().unwrap();
+/// assert_eq!(msg.message_type(), MessageType::GLL);
+/// ```
+pub struct StreamParser<const N: usize, D = NoDecoder> {
+    parser: NmeaParser<D>,
+    buffer: [u8; N],
+    /// Buffered bytes are `buffer[start..end]`
+    start: usize,
+    end: usize,
+}
+
+impl<const N: usize> StreamParser<N> {
+    /// Create a stream parser using a default `NmeaParser`
+    pub fn new() -> Self {
+        Self::with_parser(NmeaParser::new())
+    }
+}
+
+impl<const N: usize, D: MessageDecoder> StreamParser<N, D> {
+    /// Create a stream parser using a configured `NmeaParser`
+    ///
+    /// Messages are returned as produced by the decoder registered with the
+    /// parser, see `NmeaParserBuilder::decoder`.
+    pub fn with_parser(parser: NmeaParser<D>) -> Self {
+        StreamParser {
+            parser,
+            buffer: [0; N],
+            start: 0,
+            end: 0,
+        }
+    }
+
+    /// Get the wrapped parser
+    pub fn parser(&self) -> &NmeaParser<D> {
+        &self.parser
+    }
+
+    /// Append a chunk of received bytes to the buffer
+    ///
+    /// Returns the number of bytes accepted, which is less than `data.len()`
+    /// when the buffer is full. Call `next_message()` to free space and feed
+    /// the remaining bytes again.
+    pub fn feed(&mut self, data: &[u8]) -> usize {
+        if N - self.end < data.len() {
+            self.compact();
+        }
+        let count = data.len().min(N - self.end);
+        self.buffer[self.end..self.end + count].copy_from_slice(&data[..count]);
+        self.end += count;
+        count
+    }
+
+    /// Extract the next complete message from the buffered bytes
+    ///
+    /// Returns:
+    /// - `Some(Ok(message))` - Successfully parsed a complete message
+    /// - `Some(Err(error))` - Found a complete sentence but it's invalid
+    /// - `None` - No complete sentence buffered, feed more data
+    pub fn next_message(&mut self) -> Option<Result<D::Message, ParseError>> {
+        while self.end > self.start {
+            let buffered = &self.buffer[self.start..self.end];
+            match self.parser.parse_bytes(buffered) {
+                Ok((Some(msg), consumed)) => {
+                    self.consume(consumed);
+                    return Some(Ok(msg));
+                }
+                Ok((None, 0)) => {
+                    if self.parser.skips_ubx_frames()
+                        && ubx::claimed_len(buffered).is_some_and(|len| len > N)
+                    {
+                        // UBX frame that can never fit, drop its sync byte and resynchronize
+                        self.consume(1);
+                        continue;
+                    }
+                    if self.buffered() == N {
+                        // Sentence does not fit into the buffer, drop it
+                        self.clear();
+                    }
+                    return None;
+                }
+                Ok((None, consumed)) => {
+                    // Spurious data consumed
+                    self.consume(consumed);
+                }
+                Err((err, consumed)) => {
+                    self.consume(consumed);
+                    return Some(Err(err));
+                }
+            }
+        }
+        None
+    }
+
+    /// Extract the next complete message like `next_message()`, stamped with
+    /// the time read from `clock` when the message is extracted
+    ///
+    /// Errors are returned as for `next_message()`; the clock is only read
+    /// for successfully parsed messages.
+    pub fn next_message_with_clock<C: Clock>(
+        &mut self,
+        clock: &C,
+    ) -> Option<Result<Timestamped<D::Message>, ParseError>> {
+        self.next_message().map(|result| {
+            result.map(|message| Timestamped {
+                message,
+                timestamp_ms: clock.now_ms(),
+            })
+        })
+    }
+
+    /// Push a single received byte, e.g. from a UART interrupt handler
+    ///
+    /// Parsing is only attempted when a line ending is received, so most
+    /// calls just store the byte. If the buffer is full the partial sentence
+    /// it holds is discarded first.
+    ///
+    /// Returns the outcome of the sentence completed by this byte, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rustedbytes_nmea::{MessageType, StreamParser};
+    ///
+    /// let mut stream = StreamParser::<96>::new();
+    /// let mut messages = 0;
+    ///
+    /// for &byte in b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n" {
+    ///     if let Some(Ok(msg)) = stream.push_byte(byte) {
+    ///         assert_eq!(msg.message_type(), MessageType::GLL);
+    ///         messages += 1;
+    ///     }
+    /// }
+    /// assert_eq!(messages, 1);
+    /// ```
+    pub fn push_byte(&mut self, byte: u8) -> Option<Result<D::Message, ParseError>> {
+        if N == 0 {
+            return None;
+        }
+        if self.buffered() == N {
+            // Sentence does not fit into the buffer, drop it
+            self.clear();
+        } else if self.end == N {
+            self.compact();
+        }
+
+        self.buffer[self.end] = byte;
+        self.end += 1;
+
+        if byte == b'\n' || byte == b'\r' {
+            self.next_message()
+        } else {
+            None
+        }
+    }
+
+    /// Number of bytes currently buffered
+    pub fn buffered(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Discard all buffered bytes
+    pub fn clear(&mut self) {
+        self.start = 0;
+        self.end = 0;
+    }
+
+    /// Unused part of the buffer, to be filled directly by a reader
+    #[cfg(any(feature = "std", feature = "embedded-io-async"))]
+    pub(crate) fn spare_capacity(&mut self) -> &mut [u8] {
+        self.compact();
+        &mut self.buffer[self.end..]
+    }
+
+    /// Mark `count` bytes written into `spare_capacity()` as buffered
+    #[cfg(any(feature = "std", feature = "embedded-io-async"))]
+    pub(crate) fn commit(&mut self, count: usize) {
+        self.end += count;
+    }
+
+    /// Remove `count` bytes from the front of the buffered data
+    fn consume(&mut self, count: usize) {
+        self.start += count;
+        if self.start == self.end {
+            self.clear();
+        }
+    }
+
+    /// Move the buffered bytes to the front of the buffer
+    fn compact(&mut self) {
+        if self.start > 0 {
+            self.buffer.copy_within(self.start..self.end, 0);
+            self.end -= self.start;
+            self.start = 0;
+        }
+    }
+}
+
+impl<const N: usize> Default for StreamParser<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ErrorReason, MessageType};
+
+    #[test]
+    fn test_stream_split_sentence() {
+        let mut stream = StreamParser::<128>::new();
+
+        assert_eq!(stream.feed(b"noise$GPGGA,123519,4807.038,N,01131.000,"), 40);
+        assert!(stream.next_message().is_none());
+        // Spurious data before '$' is dropped, the partial sentence is kept
+        assert_eq!(stream.buffered(), 35);
+
+        stream.feed(b"E,1,08,0.9,545.4,M,46.9,M,,*47\r\n$GPGLL");
+        let msg = stream.next_message().unwrap().unwrap();
+        assert_eq!(msg.message_type(), MessageType::GGA);
+        assert!(stream.next_message().is_none());
+
+        stream.feed(b",4916.45,N,12311.12,W,225444,A,*1D\r\n");
+        let msg = stream.next_message().unwrap().unwrap();
+        assert_eq!(msg.message_type(), MessageType::GLL);
+        assert_eq!(stream.buffered(), 0);
+    }
+
+    #[test]
+    fn test_stream_compacts_partial_sentence() {
+        let mut stream = StreamParser::<64>::new();
+        let sentence = b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n";
+
+        assert_eq!(stream.feed(sentence), 42);
+        assert_eq!(stream.feed(&sentence[..20]), 20);
+        assert!(stream.next_message().unwrap().is_ok());
+        assert_eq!(stream.buffered(), 20);
+
+        // The partial sentence is moved to the front to make room for the rest
+        assert_eq!(stream.feed(&sentence[20..]), 22);
+        assert_eq!(stream.buffered(), 42);
+        assert!(stream.next_message().unwrap().is_ok());
+        assert_eq!(stream.buffered(), 0);
+
+        for &byte in &sentence[..30] {
+            assert!(stream.push_byte(byte).is_none());
+        }
+        assert_eq!(stream.feed(&sentence[30..]), 12);
+        assert!(stream.next_message().unwrap().is_ok());
+    }
+
+    #[test]
+    fn test_stream_multiple_messages_and_errors() {
+        let mut stream = StreamParser::<256>::new();
+        stream.feed(
+            b"$GPGLL,4916.45,N,12311.12,W,225444,A,*00\r\n$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n",
+        );
+
+        assert_eq!(
+            stream.next_message().unwrap().unwrap_err().reason(),
+            Some(ErrorReason::ChecksumMismatch)
+        );
+        assert!(stream.next_message().unwrap().is_ok());
+        assert!(stream.next_message().is_none());
+    }
+
+    #[test]
+    fn test_stream_bogus_ubx_header() {
+        // Sync bytes in line noise followed by a plausible 4000-byte length
+        let mut stream = StreamParser::<128>::new();
+        stream.feed(b"\xB5\x62\x01\x07\xA0\x0F$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n");
+        stream.feed(b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n");
+
+        assert!(stream.next_message().unwrap().is_ok());
+        assert!(stream.next_message().unwrap().is_ok());
+        assert!(stream.next_message().is_none());
+        assert_eq!(stream.buffered(), 0);
+
+        // A header fitting in the buffer only holds data until its checksum is received
+        let mut stream = StreamParser::<128>::new();
+        stream.feed(b"\xB5\x62\x01\x07\x08\x00$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n");
+        assert!(stream.next_message().unwrap().is_ok());
+    }
+
+    #[test]
+    fn test_stream_with_decoder() {
+        use crate::message::GenericSentence;
+        use crate::SentenceDecoder;
+
+        struct WaterTemperature;
+
+        impl SentenceDecoder for WaterTemperature {
+            type Output = f32;
+
+            fn accepts(&self, sentence: &GenericSentence) -> bool {
+                sentence.sentence_formatter() == "MTW"
+            }
+
+            fn decode(&self, sentence: &GenericSentence) -> Option<f32> {
+                sentence.field(0)?.parse().ok()
+            }
+        }
+
+        let parser = NmeaParser::builder().decoder(WaterTemperature).build();
+        let mut stream = StreamParser::<128, _>::with_parser(parser);
+        stream.feed(b"$IIMTW,18.5,C*1F\r\n$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n");
+
+        let decoded = stream.next_message().unwrap();
+        assert_eq!(decoded.unwrap().as_custom(), Some(&18.5));
+        let decoded = stream.next_message().unwrap();
+        assert_eq!(
+            decoded.unwrap().as_builtin().unwrap().message_type(),
+            MessageType::GLL
+        );
+        assert!(stream.next_message().is_none());
+
+        for &byte in b"$IIMTW,12.0,C\r\n" {
+            if let Some(decoded) = stream.push_byte(byte) {
+                assert_eq!(decoded.unwrap().as_custom(), Some(&12.0));
+            }
+        }
+    }
+
+    #[test]
+    fn test_push_byte() {
+        let mut stream = StreamParser::<128>::new();
+        let data = b"xx$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n\
+                     $GPGLL,4916.45,N,12311.12,W,225444,A,*00\r\n\
+                     $GPGLL,4916.45,N,12311.12,W,225444,A,*1D\n";
+
+        let mut results = [None, None, None];
+        let mut count = 0;
+        for &byte in data.iter() {
+            if let Some(result) = stream.push_byte(byte) {
+                results[count] = Some(result);
+                count += 1;
+            }
+        }
+
+        assert_eq!(count, 3);
+        let first = results[0].take().unwrap().unwrap();
+        assert_eq!(first.message_type(), MessageType::GGA);
+        assert_eq!(
+            results[1].take().unwrap().unwrap_err().reason(),
+            Some(ErrorReason::ChecksumMismatch)
+        );
+        assert!(results[2].take().unwrap().is_ok());
+        assert_eq!(stream.buffered(), 0);
+    }
+
+    #[test]
+    fn test_push_byte_overlong_sentence() {
+        let mut stream = StreamParser::<16>::new();
+        for &byte in b"$GPGGA,123519,4807.038,N,01131.000,E" {
+            assert!(stream.push_byte(byte).is_none());
+        }
+        assert!(stream.buffered() <= 16);
+
+        for &byte in b"\r\n$GPGLL" {
+            assert!(stream.push_byte(byte).is_none());
+        }
+        assert_eq!(stream.buffered(), 6);
+    }
+
+    #[test]
+    fn test_stream_full_buffer() {
+        let mut stream = StreamParser::<16>::new();
+
+        // Only the bytes that fit are accepted
+        assert_eq!(stream.feed(b"$GPGGA,123519,4807.038,N"), 16);
+        assert!(stream.next_message().is_none());
+        // The overlong sentence was dropped to make room
+        assert_eq!(stream.buffered(), 0);
+
+        stream.feed(b"$GPGGA");
+        stream.clear();
+        assert_eq!(stream.buffered(), 0);
+    }
+
+    #[test]
+    fn test_stream_binary_noise_does_not_stall() {
+        let mut stream = StreamParser::<256>::new();
+
+        stream.feed(b"$\xB5\x62");
+        stream.feed(&[0x01; 100]);
+        assert_eq!(
+            stream.next_message().unwrap().unwrap_err().reason(),
+            Some(ErrorReason::TooLong)
+        );
+        assert_eq!(stream.buffered(), 0);
+
+        stream.feed(b"$GPGLL,4916.45,N,12311.12,W,225444,A,*1D\r\n");
+        assert!(stream.next_message().unwrap().is_ok());
+    }
+}
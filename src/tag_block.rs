@@ -0,0 +1,192 @@
+//! NMEA 4.10 TAG block support
+//!
+//! A TAG block is an optional prefix carrying metadata about the sentence that
+//! follows it, enclosed between two backslashes:
+//!
+//! ```text
+//! \s:boat1,c:1577836800,n:42*hh\$GPGGA,...*hh
+//! ```
+//!
+//! ## Parameters
+//!
+//! | Code | Parameter | Type | Description |
+//! |------|-----------|------|-------------|
+//! | c | Timestamp | u64 | UNIX time of reception |
+//! | d | Destination | String | Destination identifier |
+//! | g | Grouping | TagGroup | Sentence number, total sentences and group ID |
+//! | n | Line count | u32 | Line count of the sentence |
+//! | r | Relative time | u64 | Relative time of reception |
+//! | s | Source | String | Source identifier |
+//! | t | Text | String | Free text |
+//!
+//! Parameters are separated by commas and the block may end with an optional
+//! `*hh` checksum computed over the characters between the backslashes.
+
+use crate::checksum;
+use crate::fixed_str::FixedStr;
+use crate::number;
+use crate::scan;
+
+/// Sentence grouping information from the `g:` TAG block parameter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TagGroup {
+    pub sentence_num: u8,
+    pub total_sentences: u8,
+    pub group_id: u32,
+}
+
+/// Parsed NMEA 4.10 TAG block
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TagBlock {
+    pub timestamp: Option<u64>,
+    pub relative_time: Option<u64>,
+    pub line_count: Option<u32>,
+    pub group: Option<TagGroup>,
+    source: FixedStr<16>,
+    destination: FixedStr<16>,
+    text: FixedStr<16>,
+}
+
+impl TagBlock {
+    /// Parse the content of a TAG block (the bytes between the two backslashes)
+    ///
+    /// Returns `None` if the block checksum does not match or a parameter is
+    /// malformed. Unknown parameter codes are ignored.
+    pub fn parse(content: &[u8]) -> Option<TagBlock> {
+        let params_end = scan::find(content, b'*').unwrap_or(content.len());
+        let params = &content[..params_end];
+
+        if params_end < content.len() {
+            let expected = checksum::decode_hex(&content[params_end + 1..])?;
+            if checksum::compute(params) != expected {
+                return None;
+            }
+        }
+
+        let mut tag = TagBlock {
+            timestamp: None,
+            relative_time: None,
+            line_count: None,
+            group: None,
+            source: FixedStr::new(b""),
+            destination: FixedStr::new(b""),
+            text: FixedStr::new(b""),
+        };
+
+        for param in params.split(|&b| b == b',') {
+            if param.is_empty() {
+                continue;
+            }
+            if param.len() < 2 || param[1] != b':' {
+                return None;
+            }
+            let value = core::str::from_utf8(&param[2..]).ok()?;
+
+            match param[0] {
+                b'c' => tag.timestamp = Some(number::parse(value)?),
+                b'r' => tag.relative_time = Some(number::parse(value)?),
+                b'n' => tag.line_count = Some(number::parse(value)?),
+                b'g' => tag.group = Some(parse_group(value)?),
+                b's' => tag.source = FixedStr::new(value.as_bytes()),
+                b'd' => tag.destination = FixedStr::new(value.as_bytes()),
+                b't' => tag.text = FixedStr::new(value.as_bytes()),
+                _ => {}
+            }
+        }
+
+        Some(tag)
+    }
+
+    /// Get source identifier as string slice (if present)
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_opt_str()
+    }
+
+    /// Get destination identifier as string slice (if present)
+    pub fn destination(&self) -> Option<&str> {
+        self.destination.as_opt_str()
+    }
+
+    /// Get free text as string slice (if present, truncated to 16 bytes)
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_opt_str()
+    }
+}
+
+/// Parse a `g:` grouping value in the `sentence-total-id` format
+fn parse_group(value: &str) -> Option<TagGroup> {
+    let mut parts = value.split('-');
+    let group = TagGroup {
+        sentence_num: number::parse(parts.next()?)?,
+        total_sentences: number::parse(parts.next()?)?,
+        group_id: number::parse(parts.next()?)?,
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(group)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tag_block_all_parameters() {
+        let tag = TagBlock::parse(b"s:boat1,d:shore,c:1577836800,n:42,r:1234,t:hello").unwrap();
+
+        assert_eq!(tag.source(), Some("boat1"));
+        assert_eq!(tag.destination(), Some("shore"));
+        assert_eq!(tag.timestamp, Some(1577836800));
+        assert_eq!(tag.line_count, Some(42));
+        assert_eq!(tag.relative_time, Some(1234));
+        assert_eq!(tag.text(), Some("hello"));
+        assert_eq!(tag.group, None);
+    }
+
+    #[test]
+    fn test_tag_block_with_checksum() {
+        let tag = TagBlock::parse(b"s:r3669961,c:1120959341*7B").unwrap();
+
+        assert_eq!(tag.source(), Some("r3669961"));
+        assert_eq!(tag.timestamp, Some(1120959341));
+    }
+
+    #[test]
+    fn test_tag_block_wrong_checksum() {
+        assert!(TagBlock::parse(b"s:r3669961,c:1120959341*00").is_none());
+    }
+
+    #[test]
+    fn test_tag_block_group() {
+        let tag = TagBlock::parse(b"g:1-2-1234,n:5").unwrap();
+
+        assert_eq!(
+            tag.group,
+            Some(TagGroup {
+                sentence_num: 1,
+                total_sentences: 2,
+                group_id: 1234,
+            })
+        );
+        assert_eq!(tag.line_count, Some(5));
+    }
+
+    #[test]
+    fn test_tag_block_malformed_parameter() {
+        assert!(TagBlock::parse(b"c:notanumber").is_none());
+        assert!(TagBlock::parse(b"source").is_none());
+        assert!(TagBlock::parse(b"g:1-2").is_none());
+    }
+
+    #[test]
+    fn test_tag_block_unknown_parameter_ignored() {
+        let tag = TagBlock::parse(b"x:whatever,s:src").unwrap();
+
+        assert_eq!(tag.source(), Some("src"));
+    }
+}
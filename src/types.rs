@@ -1,17 +1,183 @@
 //! NMEA message types and data structures
 
-use crate::message::{GgaData, GllData, GnsData, GsaData, GsvData, RmcData, VtgData};
+use core::fmt;
+
+use crate::encode::EncodeError;
+use crate::message::{
+    GenericSentence, GgaData, GllData, GnsData, GsaData, GsvData, ProprietaryData, QueryData,
+    RmcData, VtgData,
+};
 
 /// Parse error types
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum ParseError {
     /// Checksum verification failed
-    InvalidChecksum,
+    InvalidChecksum(ErrorDetail),
     /// Message is syntactically complete but missing mandatory fields
-    InvalidMessage,
+    InvalidMessage(ErrorDetail),
+    /// Sentence was consumed without decoding because the parser filters it out
+    Skipped,
+}
+
+impl ParseError {
+    /// Get the location and reason of the failure (`None` for `Skipped`)
+    pub fn detail(&self) -> Option<ErrorDetail> {
+        match self {
+            ParseError::InvalidChecksum(detail) | ParseError::InvalidMessage(detail) => {
+                Some(*detail)
+            }
+            ParseError::Skipped => None,
+        }
+    }
+
+    /// Get the reason of the failure (`None` for `Skipped`)
+    pub fn reason(&self) -> Option<ErrorReason> {
+        self.detail().map(|detail| detail.reason)
+    }
+}
+
+/// Location and reason of a rejected sentence
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ErrorDetail {
+    /// Why the sentence was rejected
+    pub reason: ErrorReason,
+    /// Index of the failing field (0 is the address field), `None` if the
+    /// failure does not concern a single field
+    pub field: Option<u8>,
+    /// Byte offset of the failure within the sentence, counted from the `$`
+    pub offset: usize,
+}
+
+impl ErrorDetail {
+    /// Create a detail for a failure at `offset` not tied to a field
+    pub(crate) fn at(reason: ErrorReason, offset: usize) -> Self {
+        ErrorDetail {
+            reason,
+            field: None,
+            offset,
+        }
+    }
+
+    /// Create a detail for a failing field starting at `offset`
+    pub(crate) fn in_field(reason: ErrorReason, field: usize, offset: usize) -> Self {
+        ErrorDetail {
+            reason,
+            field: Some(field.min(u8::MAX as usize) as u8),
+            offset,
+        }
+    }
 }
 
-/// Represents the GNSS constellation (talker ID)
+/// Reason of a rejected sentence
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ErrorReason {
+    /// The sentence does not start with the `$` start delimiter
+    MissingStart,
+    /// The `*hh` checksum does not match the sentence content
+    ChecksumMismatch,
+    /// The characters after `*` are not two hexadecimal digits
+    MalformedChecksum,
+    /// The sentence has no checksum but `ChecksumPolicy::Strict` requires one
+    MissingChecksum,
+    /// A mandatory field is empty or missing
+    MissingField,
+    /// A numeric field could not be converted
+    InvalidNumber,
+    /// A field does not hold an accepted value
+    InvalidValue,
+    /// The address field is too short to identify the sentence type
+    UnknownType,
+    /// The sentence is too long to be stored
+    TooLong,
+    /// A custom `SentenceDecoder` accepted the sentence but could not decode it
+    DecoderFailed,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidChecksum(detail) => {
+                write!(f, "checksum verification failed: {}", detail)
+            }
+            ParseError::InvalidMessage(detail) => {
+                write!(f, "invalid or incomplete sentence: {}", detail)
+            }
+            ParseError::Skipped => f.write_str("sentence skipped by parser filters"),
+        }
+    }
+}
+
+impl fmt::Display for ErrorDetail {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.reason)?;
+        if let Some(field) = self.field {
+            write!(f, " in field {}", field)?;
+        }
+        write!(f, " at byte {}", self.offset)
+    }
+}
+
+impl fmt::Display for ErrorReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ErrorReason::MissingStart => "missing start delimiter",
+            ErrorReason::ChecksumMismatch => "checksum mismatch",
+            ErrorReason::MalformedChecksum => "malformed checksum",
+            ErrorReason::MissingChecksum => "missing checksum",
+            ErrorReason::MissingField => "missing mandatory field",
+            ErrorReason::InvalidNumber => "invalid number",
+            ErrorReason::InvalidValue => "invalid value",
+            ErrorReason::UnknownType => "unknown sentence type",
+            ErrorReason::TooLong => "sentence too long",
+            ErrorReason::DecoderFailed => "custom decoder failed",
+        })
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+/// Buffer-retention hint returned by `NmeaParser::parse_bytes_hinted`
+///
+/// Tells the caller how much of its buffer can be dropped and, once the
+/// checksum marker of a partial sentence has been received, how many more
+/// bytes a valid sentence needs at least.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BufferHint {
+    /// Leading bytes that can be discarded (spurious data or the parsed sentence)
+    pub discard: usize,
+    /// Minimum number of additional bytes needed to complete the partial
+    /// sentence, `None` if the buffer holds no partial sentence or its length
+    /// cannot be derived yet
+    pub min_needed: Option<usize>,
+}
+
+/// Checksum verification policy
+///
+/// - `Strict` rejects sentences with a missing or wrong checksum
+/// - `Lenient` accepts sentences without checksum but rejects wrong ones (default)
+/// - `Off` never verifies checksums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ChecksumPolicy {
+    Strict,
+    #[default]
+    Lenient,
+    Off,
+}
+
+/// Represents the talker ID (GNSS constellation or instrument)
+///
+/// Talkers not recognized by the library keep their two raw address
+/// characters in `Unknown`, so mixed instrument buses can still be attributed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TalkerId {
     GP, // GPS
@@ -21,24 +187,328 @@ pub enum TalkerId {
     GN, // Multi-GNSS (combined)
     BD, // BeiDou (alternative)
     QZ, // QZSS (Quasi-Zenith Satellite System)
+    GQ, // QZSS (NMEA 4.11)
+    GI, // NavIC (IRNSS)
+    AG, // Autopilot - General
+    AP, // Autopilot - Magnetic
+    AI, // Automatic Identification System (AIS)
+    CC, // Computer - Programmed calculator
+    EC, // Electronic Chart Display & Information System (ECDIS)
+    HC, // Heading - Magnetic compass
+    HE, // Heading - North seeking gyro
+    HN, // Heading - Non north seeking gyro
+    II, // Integrated Instrumentation
+    IN, // Integrated Navigation
+    SD, // Depth sounder
+    TI, // Turn rate indicator
+    VD, // Velocity sensor - Doppler
+    VM, // Velocity sensor - Speed log, water, magnetic
+    VW, // Velocity sensor - Speed log, water, mechanical
+    WI, // Weather instruments
+    YX, // Transducer
+    /// Unrecognized talker, raw address characters
+    Unknown([u8; 2]),
+}
+
+impl TalkerId {
+    /// Identify the talker from the two address characters
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Self {
+        match bytes {
+            b"GP" => TalkerId::GP,
+            b"GL" => TalkerId::GL,
+            b"GA" => TalkerId::GA,
+            b"GB" => TalkerId::GB,
+            b"GN" => TalkerId::GN,
+            b"BD" => TalkerId::BD,
+            b"QZ" => TalkerId::QZ,
+            b"GQ" => TalkerId::GQ,
+            b"GI" => TalkerId::GI,
+            b"AG" => TalkerId::AG,
+            b"AP" => TalkerId::AP,
+            b"AI" => TalkerId::AI,
+            b"CC" => TalkerId::CC,
+            b"EC" => TalkerId::EC,
+            b"HC" => TalkerId::HC,
+            b"HE" => TalkerId::HE,
+            b"HN" => TalkerId::HN,
+            b"II" => TalkerId::II,
+            b"IN" => TalkerId::IN,
+            b"SD" => TalkerId::SD,
+            b"TI" => TalkerId::TI,
+            b"VD" => TalkerId::VD,
+            b"VM" => TalkerId::VM,
+            b"VW" => TalkerId::VW,
+            b"WI" => TalkerId::WI,
+            b"YX" => TalkerId::YX,
+            _ => {
+                let mut raw = [0u8; 2];
+                let len = bytes.len().min(2);
+                raw[..len].copy_from_slice(&bytes[..len]);
+                TalkerId::Unknown(raw)
+            }
+        }
+    }
+
+    /// Get the two-character talker identifier as string slice
+    ///
+    /// For unrecognized talkers this returns the raw address characters.
+    pub fn as_str(&self) -> &str {
+        match self {
+            TalkerId::GP => "GP",
+            TalkerId::GL => "GL",
+            TalkerId::GA => "GA",
+            TalkerId::GB => "GB",
+            TalkerId::GN => "GN",
+            TalkerId::BD => "BD",
+            TalkerId::QZ => "QZ",
+            TalkerId::GQ => "GQ",
+            TalkerId::GI => "GI",
+            TalkerId::AG => "AG",
+            TalkerId::AP => "AP",
+            TalkerId::AI => "AI",
+            TalkerId::CC => "CC",
+            TalkerId::EC => "EC",
+            TalkerId::HC => "HC",
+            TalkerId::HE => "HE",
+            TalkerId::HN => "HN",
+            TalkerId::II => "II",
+            TalkerId::IN => "IN",
+            TalkerId::SD => "SD",
+            TalkerId::TI => "TI",
+            TalkerId::VD => "VD",
+            TalkerId::VM => "VM",
+            TalkerId::VW => "VW",
+            TalkerId::WI => "WI",
+            TalkerId::YX => "YX",
+            TalkerId::Unknown(raw) => core::str::from_utf8(raw).unwrap_or(""),
+        }
+    }
+
+    /// Check whether the talker is a satellite navigation system
+    pub fn is_gnss(&self) -> bool {
+        matches!(
+            self,
+            TalkerId::GP
+                | TalkerId::GL
+                | TalkerId::GA
+                | TalkerId::GB
+                | TalkerId::GN
+                | TalkerId::BD
+                | TalkerId::QZ
+                | TalkerId::GQ
+                | TalkerId::GI
+        )
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for TalkerId {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{=str}", self.as_str())
+    }
+}
+
+/// Represents the GNSS system identifier (NMEA 4.11 System ID field)
+///
+/// Used by multi-constellation receivers to attribute `GN`-talker sentences
+/// to the constellation they describe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SystemId {
+    Gps,     // 1
+    Glonass, // 2
+    Galileo, // 3
+    BeiDou,  // 4
+    Qzss,    // 5
+    NavIc,   // 6
+    Unknown,
+}
+
+impl SystemId {
+    /// Map a numeric NMEA System ID to the corresponding constellation
+    pub fn from_id(id: u8) -> Self {
+        match id {
+            1 => SystemId::Gps,
+            2 => SystemId::Glonass,
+            3 => SystemId::Galileo,
+            4 => SystemId::BeiDou,
+            5 => SystemId::Qzss,
+            6 => SystemId::NavIc,
+            _ => SystemId::Unknown,
+        }
+    }
+
+    /// Numeric NMEA System ID (`None` for `Unknown`)
+    pub(crate) fn id(&self) -> Option<u8> {
+        match self {
+            SystemId::Gps => Some(1),
+            SystemId::Glonass => Some(2),
+            SystemId::Galileo => Some(3),
+            SystemId::BeiDou => Some(4),
+            SystemId::Qzss => Some(5),
+            SystemId::NavIc => Some(6),
+            SystemId::Unknown => None,
+        }
+    }
+}
+
+/// GGA fix quality indicator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FixQuality {
+    Invalid,    // 0
+    Gps,        // 1 - Autonomous GNSS fix
+    Dgps,       // 2 - Differential GNSS fix
+    Pps,        // 3 - PPS fix
+    RtkFixed,   // 4 - Real Time Kinematic, fixed integers
+    RtkFloat,   // 5 - Real Time Kinematic, float integers
+    Estimated,  // 6 - Estimated (dead reckoning)
+    Manual,     // 7 - Manual input mode
+    Simulation, // 8 - Simulation mode
+    Unknown,
+}
+
+impl FixQuality {
+    /// Map a raw GGA fix quality value to the corresponding indicator
+    pub fn from_u8(value: u8) -> Self {
+        match value {
+            0 => FixQuality::Invalid,
+            1 => FixQuality::Gps,
+            2 => FixQuality::Dgps,
+            3 => FixQuality::Pps,
+            4 => FixQuality::RtkFixed,
+            5 => FixQuality::RtkFloat,
+            6 => FixQuality::Estimated,
+            7 => FixQuality::Manual,
+            8 => FixQuality::Simulation,
+            _ => FixQuality::Unknown,
+        }
+    }
+
+    /// Raw GGA fix quality value, `None` for `Unknown`
+    pub(crate) fn to_u8(self) -> Option<u8> {
+        match self {
+            FixQuality::Invalid => Some(0),
+            FixQuality::Gps => Some(1),
+            FixQuality::Dgps => Some(2),
+            FixQuality::Pps => Some(3),
+            FixQuality::RtkFixed => Some(4),
+            FixQuality::RtkFloat => Some(5),
+            FixQuality::Estimated => Some(6),
+            FixQuality::Manual => Some(7),
+            FixQuality::Simulation => Some(8),
+            FixQuality::Unknown => None,
+        }
+    }
+}
+
+/// GSA fix type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FixType {
+    NoFix, // 1
+    Fix2D, // 2
+    Fix3D, // 3
     Unknown,
 }
 
+impl FixType {
+    /// Map a raw GSA fix type value to the corresponding fix type
+    pub fn from_u8(value: u8) -> Self {
+        match value {
+            1 => FixType::NoFix,
+            2 => FixType::Fix2D,
+            3 => FixType::Fix3D,
+            _ => FixType::Unknown,
+        }
+    }
+
+    /// Raw GSA fix type value, `None` for `Unknown`
+    pub(crate) fn to_u8(self) -> Option<u8> {
+        match self {
+            FixType::NoFix => Some(1),
+            FixType::Fix2D => Some(2),
+            FixType::Fix3D => Some(3),
+            FixType::Unknown => None,
+        }
+    }
+}
+
+/// Data status indicator used by RMC and GLL
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Status {
+    Valid,   // A - Data valid
+    Invalid, // V - Data not valid (void)
+    Unknown,
+}
+
+impl Status {
+    /// Map a raw status character to the corresponding status
+    pub fn from_char(value: char) -> Self {
+        match value {
+            'A' => Status::Valid,
+            'V' => Status::Invalid,
+            _ => Status::Unknown,
+        }
+    }
+
+    /// Raw status character, `None` for `Unknown`
+    pub(crate) fn to_char(self) -> Option<char> {
+        match self {
+            Status::Valid => Some('A'),
+            Status::Invalid => Some('V'),
+            Status::Unknown => None,
+        }
+    }
+}
+
 /// Represents the different NMEA message type identifiers
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum MessageType {
-    GGA, // Global Positioning System Fix Data
-    RMC, // Recommended Minimum Navigation Information
-    GSA, // GPS DOP and active satellites
-    GSV, // GPS Satellites in view
-    GLL, // Geographic Position - Latitude/Longitude
-    VTG, // Track Made Good and Ground Speed
-    GNS, // GNSS Fix Data
+    GGA,         // Global Positioning System Fix Data
+    RMC,         // Recommended Minimum Navigation Information
+    GSA,         // GPS DOP and active satellites
+    GSV,         // GPS Satellites in view
+    GLL,         // Geographic Position - Latitude/Longitude
+    VTG,         // Track Made Good and Ground Speed
+    GNS,         // GNSS Fix Data
+    Query,       // Query sentence ($ttllQ)
+    Proprietary, // Proprietary sentence ($P)
     Unknown,
 }
 
+impl MessageType {
+    /// Bit identifying the message type in a message-type filter
+    pub(crate) fn mask(self) -> u16 {
+        1 << (self as u16)
+    }
+
+    /// Identify the message type from a three-character sentence formatter
+    pub(crate) fn from_formatter(bytes: &[u8]) -> Self {
+        match bytes {
+            b"GGA" => MessageType::GGA,
+            b"RMC" => MessageType::RMC,
+            b"GSA" => MessageType::GSA,
+            b"GSV" => MessageType::GSV,
+            b"GLL" => MessageType::GLL,
+            b"VTG" => MessageType::VTG,
+            b"GNS" => MessageType::GNS,
+            _ => MessageType::Unknown,
+        }
+    }
+}
+
 /// Parsed NMEA message with associated data
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum NmeaMessage {
     GGA(GgaData),
     RMC(RmcData),
@@ -47,6 +517,10 @@ pub enum NmeaMessage {
     GLL(GllData),
     VTG(VtgData),
     GNS(GnsData),
+    Query(QueryData),
+    Proprietary(ProprietaryData),
+    /// Sentence of a type not decoded by the library
+    Unknown(GenericSentence),
 }
 
 impl NmeaMessage {
@@ -60,6 +534,9 @@ impl NmeaMessage {
             NmeaMessage::GLL(_) => MessageType::GLL,
             NmeaMessage::VTG(_) => MessageType::VTG,
             NmeaMessage::GNS(_) => MessageType::GNS,
+            NmeaMessage::Query(_) => MessageType::Query,
+            NmeaMessage::Proprietary(_) => MessageType::Proprietary,
+            NmeaMessage::Unknown(_) => MessageType::Unknown,
         }
     }
 
@@ -73,6 +550,9 @@ impl NmeaMessage {
             NmeaMessage::GLL(d) => d.talker_id,
             NmeaMessage::VTG(d) => d.talker_id,
             NmeaMessage::GNS(d) => d.talker_id,
+            NmeaMessage::Query(d) => d.talker_id,
+            NmeaMessage::Proprietary(d) => d.as_generic().talker_id,
+            NmeaMessage::Unknown(d) => d.talker_id,
         }
     }
 
@@ -138,4 +618,94 @@ impl NmeaMessage {
             None
         }
     }
+
+    /// Extract query data if this is a query sentence
+    pub fn as_query(&self) -> Option<&QueryData> {
+        if let NmeaMessage::Query(data) = self {
+            Some(data)
+        } else {
+            None
+        }
+    }
+
+    /// Extract proprietary sentence data if this is a proprietary sentence
+    pub fn as_proprietary(&self) -> Option<&ProprietaryData> {
+        if let NmeaMessage::Proprietary(data) = self {
+            Some(data)
+        } else {
+            None
+        }
+    }
+
+    /// Extract the generic sentence if this sentence type is not decoded by the library
+    pub fn as_unknown(&self) -> Option<&GenericSentence> {
+        if let NmeaMessage::Unknown(data) = self {
+            Some(data)
+        } else {
+            None
+        }
+    }
+
+    /// Get the raw fields of unknown and proprietary sentences as a `GenericSentence`
+    pub fn as_generic(&self) -> Option<&GenericSentence> {
+        match self {
+            NmeaMessage::Unknown(data) => Some(data),
+            NmeaMessage::Proprietary(data) => Some(data.as_generic()),
+            _ => None,
+        }
+    }
+
+    /// Encode the message as an NMEA sentence into `buffer`
+    ///
+    /// Writes the complete sentence, from `$` to the checksum and `<CR><LF>`,
+    /// and returns its length. Unknown and proprietary sentences are written
+    /// with their raw fields.
+    pub fn encode(&self, buffer: &mut [u8]) -> Result<usize, EncodeError> {
+        match self {
+            NmeaMessage::GGA(d) => d.encode(buffer),
+            NmeaMessage::RMC(d) => d.encode(buffer),
+            NmeaMessage::GSA(d) => d.encode(buffer),
+            NmeaMessage::GSV(d) => d.encode(buffer),
+            NmeaMessage::GLL(d) => d.encode(buffer),
+            NmeaMessage::VTG(d) => d.encode(buffer),
+            NmeaMessage::GNS(d) => d.encode(buffer),
+            NmeaMessage::Query(d) => d.encode(buffer),
+            NmeaMessage::Proprietary(d) => d.encode(buffer),
+            NmeaMessage::Unknown(d) => d.encode(buffer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::NmeaParser;
+    use crate::ParseError;
+    use std::string::ToString;
+
+    #[test]
+    fn test_parse_error_display() {
+        let parser = NmeaParser::new();
+
+        let (err, _) = parser
+            .parse_bytes(b"$GPGLL,4916.45,N,12311.12,W,225444,A,*00\r\n")
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "checksum verification failed: checksum mismatch at byte 37"
+        );
+
+        let (err, _) = parser
+            .parse_bytes(b"$GPGLL,4916.45,N,,W,225444,A,*00\r\n")
+            .unwrap_err();
+        let err: &dyn core::error::Error = &err;
+        assert_eq!(
+            err.to_string(),
+            "invalid or incomplete sentence: missing mandatory field in field 3 at byte 17"
+        );
+
+        assert_eq!(
+            ParseError::Skipped.to_string(),
+            "sentence skipped by parser filters"
+        );
+    }
 }
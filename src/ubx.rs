@@ -0,0 +1,138 @@
+//! u-blox UBX binary frame detection
+//!
+//! Many u-blox receivers interleave binary UBX frames with their NMEA output
+//! on the same port. A UBX frame has the following layout:
+//!
+//! ```text
+//! 0xB5 0x62 | class | id | length (u16, little endian) | payload | CK_A | CK_B
+//! ```
+//!
+//! The two checksum bytes are an 8-bit Fletcher checksum computed over class,
+//! id, length and payload. Recognizing whole frames lets the parser skip them
+//! atomically: a `$` byte inside a binary payload would otherwise be taken for
+//! the start of a sentence.
+
+/// First UBX sync character
+pub(crate) const SYNC_1: u8 = 0xB5;
+/// Second UBX sync character
+const SYNC_2: u8 = 0x62;
+/// Sync characters, class, id and length
+const HEADER_LEN: usize = 6;
+/// Largest payload accepted as a UBX frame; longer lengths are taken as noise
+const MAX_PAYLOAD_LEN: usize = 4096;
+
+/// Outcome of checking for a UBX frame at the start of a buffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UbxFrame {
+    /// The data does not start with a valid UBX frame
+    NotUbx,
+    /// The data may start with a UBX frame but more bytes are needed
+    Partial,
+    /// A complete UBX frame of the given length (including sync and checksum)
+    Complete(usize),
+}
+
+/// Check whether `data` starts with a UBX frame
+pub(crate) fn frame(data: &[u8]) -> UbxFrame {
+    if data.first() != Some(&SYNC_1) {
+        return UbxFrame::NotUbx;
+    }
+    match data.get(1) {
+        None => return UbxFrame::Partial,
+        Some(&SYNC_2) => {}
+        Some(_) => return UbxFrame::NotUbx,
+    }
+    if data.len() < HEADER_LEN {
+        return UbxFrame::Partial;
+    }
+
+    let payload_len = u16::from_le_bytes([data[4], data[5]]) as usize;
+    if payload_len > MAX_PAYLOAD_LEN {
+        return UbxFrame::NotUbx;
+    }
+
+    let frame_len = HEADER_LEN + payload_len + 2;
+    if data.len() < frame_len {
+        return UbxFrame::Partial;
+    }
+
+    let (ck_a, ck_b) = fletcher(&data[2..HEADER_LEN + payload_len]);
+    if data[frame_len - 2] == ck_a && data[frame_len - 1] == ck_b {
+        UbxFrame::Complete(frame_len)
+    } else {
+        UbxFrame::NotUbx
+    }
+}
+
+/// Frame length (including sync and checksum) claimed by a UBX header at the
+/// start of `data`, `None` if `data` does not start with a complete header
+///
+/// The frame is not validated: this tells a stream buffer whether a partial
+/// frame can ever complete within its capacity.
+pub(crate) fn claimed_len(data: &[u8]) -> Option<usize> {
+    match data {
+        [SYNC_1, SYNC_2, _, _, len_lo, len_hi, ..] => {
+            Some(HEADER_LEN + u16::from_le_bytes([*len_lo, *len_hi]) as usize + 2)
+        }
+        _ => None,
+    }
+}
+
+/// 8-bit Fletcher checksum used by UBX frames
+fn fletcher(data: &[u8]) -> (u8, u8) {
+    data.iter().fold((0u8, 0u8), |(ck_a, ck_b), &b| {
+        let ck_a = ck_a.wrapping_add(b);
+        (ck_a, ck_b.wrapping_add(ck_a))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// UBX-NAV-STATUS poll request
+    const POLL: [u8; 8] = [0xB5, 0x62, 0x01, 0x03, 0x00, 0x00, 0x04, 0x0D];
+
+    #[test]
+    fn test_ubx_complete_frame() {
+        assert_eq!(frame(&POLL), UbxFrame::Complete(8));
+
+        // Payload containing a '$'
+        let frame_data = [0xB5, 0x62, 0x06, 0x01, 0x02, 0x00, b'$', 0x01, 0x2E, 0x7A];
+        assert_eq!(frame(&frame_data), UbxFrame::Complete(10));
+    }
+
+    #[test]
+    fn test_ubx_partial_frame() {
+        for len in 1..POLL.len() {
+            assert_eq!(frame(&POLL[..len]), UbxFrame::Partial);
+        }
+    }
+
+    #[test]
+    fn test_ubx_not_a_frame() {
+        assert_eq!(frame(b"$GPGGA"), UbxFrame::NotUbx);
+        assert_eq!(frame(&[0xB5, 0x00]), UbxFrame::NotUbx);
+
+        let mut bad_checksum = POLL;
+        bad_checksum[7] ^= 0xFF;
+        assert_eq!(frame(&bad_checksum), UbxFrame::NotUbx);
+
+        // Implausible payload length
+        assert_eq!(
+            frame(&[0xB5, 0x62, 0x01, 0x03, 0xFF, 0xFF]),
+            UbxFrame::NotUbx
+        );
+    }
+
+    #[test]
+    fn test_ubx_claimed_len() {
+        assert_eq!(claimed_len(&POLL), Some(8));
+        assert_eq!(
+            claimed_len(&[0xB5, 0x62, 0x01, 0x03, 0x00, 0x01]),
+            Some(264)
+        );
+        assert_eq!(claimed_len(&POLL[..5]), None);
+        assert_eq!(claimed_len(b"$GPGGA"), None);
+    }
+}